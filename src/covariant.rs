@@ -0,0 +1,90 @@
+/// Covariant, read-only variant of [`AlwaysSend`](crate::AlwaysSend).
+///
+/// Like `AlwaysSend`, this type only requires `T: Send` on construction,
+/// and then implements `Send` unconditionally. Unlike `AlwaysSend`, the type
+/// parameter `T` is *covariant*, so e.g. an `AlwaysSendCovariant<&'static str>`
+/// coerces into an `AlwaysSendCovariant<&'a str>`.
+///
+/// **Beware** that such subtyping coercions do change the type `T`, and the
+/// new `T` was never checked to be `Send`. Since this wrapper gives *no* mutable
+/// access to its inner value, the value that ends up being sent is always the one
+/// that was wrapped on construction, back when its original type passed the
+/// `T: Send` check. That's only enough as long as every subtype of a `Send` type
+/// is `Send`, too, which holds unless some `Send` implementation involved singles
+/// out specific lifetimes (or higher-ranked function pointer types).
+///
+/// It does *not* hold e.g. for a type `Foo<'a>` with nothing but an
+/// `unsafe impl Send for Foo<'static> {}`: an `AlwaysSendCovariant<Foo<'static>>`
+/// can be coerced into an `AlwaysSendCovariant<Foo<'a>>` and sent to another thread,
+/// and [`into_inner`](Self::into_inner) then hands out the `!Send` `Foo<'a>` on
+/// that thread. Don't use this wrapper with such types.
+///
+/// Access to the inner value is limited to [`get`](Self::get) and
+/// [`into_inner`](Self::into_inner); there is no `&mut` or pinned access while
+/// it's wrapped, and hence no `Future` or `Stream` implementation either; for
+/// those, use the invariant `AlwaysSend`.
+///
+/// # Examples
+///
+/// ```
+/// use always_send::AlwaysSendCovariant;
+///
+/// fn assert_send<T: Send>(_: &T) {}
+///
+/// fn shorten<'a>(x: AlwaysSendCovariant<&'static str>) -> AlwaysSendCovariant<&'a str> {
+///     x
+/// }
+///
+/// let wrapped = shorten(AlwaysSendCovariant::new("hello"));
+/// assert_send(&wrapped);
+/// assert_eq!(*wrapped.get(), "hello");
+///
+/// // the same goes for higher-ranked subtyping
+/// fn specialize(
+///     x: AlwaysSendCovariant<for<'a> fn(&'a str)>,
+/// ) -> AlwaysSendCovariant<fn(&'static str)> {
+///     x
+/// }
+/// ```
+///
+/// whereas the invariant `AlwaysSend` doesn't allow such coercions
+///
+/// ```compile_fail,E0308
+/// use always_send::AlwaysSend;
+///
+/// fn specialize(x: AlwaysSend<for<'a> fn(&'a str)>) -> AlwaysSend<fn(&'static str)> {
+///     x
+/// }
+/// ```
+///
+/// (The `&'static str` to `&'a str` case fails to compile as well, with
+/// "lifetime may not live long enough", but that error has no code to check for.)
+pub struct AlwaysSendCovariant<T> {
+    // private, so there's no mutable access, and no construction other than `new`
+    inner: T,
+}
+
+// SAFETY: construction requires `T: Send`, and the lack of any mutable access
+// ensures that after subtyping coercions, the contained value is still the original one
+// that was checked on construction. This relies on subtypes of `Send` types being `Send`,
+// which `Send` impls for specific lifetimes can break. (See the type's documentation.)
+unsafe impl<T> Send for AlwaysSendCovariant<T> {}
+
+impl<T: Send> AlwaysSendCovariant<T> {
+    /// Wraps sendable type in the [`AlwaysSendCovariant<T>`] wrapper.
+    pub fn new(inner: T) -> Self {
+        Self { inner }
+    }
+}
+
+impl<T> AlwaysSendCovariant<T> {
+    /// Shared access to the inner value.
+    pub fn get(&self) -> &T {
+        &self.inner
+    }
+
+    /// Unwraps the inner value.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
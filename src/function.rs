@@ -0,0 +1,368 @@
+use core::marker::PhantomData;
+
+/// Wrapper type around some [`Send`] closure (or other function-like value).
+///
+/// This is the counterpart of [`AlwaysSend`](crate::AlwaysSend) for the case where
+/// the cleanest fix is to make the closure itself unconditionally `Send`,
+/// rather than the future (or stream) it ends up in.
+///
+/// Like `AlwaysSend`, this type only requires `F: Send` on construction,
+/// and features an *invariant* type parameter.
+#[repr(transparent)]
+pub struct AlwaysSendFn<F> {
+    /// The wrapped closure.
+    pub inner: F,
+    marker: PhantomData<fn() -> *mut F>,
+}
+
+// SAFETY: the only way of obtaining an `AlwaysSendFn` is the `F: Send`-bound
+// constructor below, the same reasoning as for `AlwaysSend` applies.
+unsafe impl<F> Send for AlwaysSendFn<F> {}
+
+impl<F: Unpin> Unpin for AlwaysSendFn<F> {}
+
+impl<F: Send> AlwaysSendFn<F> {
+    /// Wraps sendable closure in the [`AlwaysSendFn<F>`] wrapper.
+    pub fn new(inner: F) -> Self {
+        Self {
+            inner,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<F> AlwaysSendFn<F> {
+    /// Calls the wrapped closure by shared reference, with the arguments given as a tuple.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use always_send::AlwaysSendFn;
+    ///
+    /// let add = AlwaysSendFn::new(|x: i32, y: i32| x + y);
+    /// assert_eq!(add.call((1, 2)), 3);
+    /// ```
+    pub fn call<Args>(&self, args: Args) -> F::Output
+    where
+        F: Call<Args>,
+    {
+        self.inner.call(args)
+    }
+
+    /// Calls the wrapped closure by mutable reference, with the arguments given as a tuple.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use always_send::AlwaysSendFn;
+    ///
+    /// let mut total = 0;
+    /// let mut add = AlwaysSendFn::new(|x: i32| total += x);
+    /// add.call_mut((1,));
+    /// add.call_mut((2,));
+    /// drop(add);
+    /// assert_eq!(total, 3);
+    /// ```
+    pub fn call_mut<Args>(&mut self, args: Args) -> F::Output
+    where
+        F: CallMut<Args>,
+    {
+        self.inner.call_mut(args)
+    }
+
+    /// Calls the wrapped closure by value, with the arguments given as a tuple.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use always_send::AlwaysSendFn;
+    ///
+    /// let s = String::from("hello");
+    /// let into_len = AlwaysSendFn::new(move || s.into_bytes().len());
+    /// assert_eq!(into_len.call_once(()), 5);
+    /// ```
+    pub fn call_once<Args>(self, args: Args) -> F::Output
+    where
+        F: CallOnce<Args>,
+    {
+        self.inner.call_once(args)
+    }
+
+    /// Turns this wrapper into a single-argument closure that's still unconditionally
+    /// `Send`, for passing to combinators expecting an `Fn(A) -> R`.
+    ///
+    /// # Examples
+    ///
+    /// A closure capturing a stream of futures over borrowed items can run into
+    /// [rust-lang/rust#89976] once the surrounding future needs to be `Send`:
+    ///
+    /// ```compile_fail
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// use futures::stream::{self, Stream, StreamExt as _};
+    ///
+    /// let handle = tokio::spawn(async {
+    ///     let v = vec![1, 2, 3];
+    ///     let doubled = stream::iter(&v).map(|&x| async move { x * 2 }).buffer_unordered(2);
+    ///     let add_pending = move |x: usize| x + doubled.size_hint().0;
+    ///     stream::iter(0..3).map(add_pending).collect::<Vec<_>>().await
+    /// });
+    /// assert_eq!(handle.await.unwrap(), [3, 4, 5]);
+    /// # }
+    /// ```
+    ///
+    /// Checking `Send` for the closure right where it's created fixes this:
+    ///
+    /// ```
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// use always_send::FnExt as _;
+    /// use futures::stream::{self, Stream, StreamExt as _};
+    ///
+    /// let handle = tokio::spawn(async {
+    ///     let v = vec![1, 2, 3];
+    ///     let doubled = stream::iter(&v).map(|&x| async move { x * 2 }).buffer_unordered(2);
+    ///     let add_pending = move |x: usize| x + doubled.size_hint().0;
+    ///     let add_pending = add_pending.always_send_fn().into_fn();
+    ///     stream::iter(0..3).map(add_pending).collect::<Vec<_>>().await
+    /// });
+    /// assert_eq!(handle.await.unwrap(), [3, 4, 5]);
+    /// # }
+    /// ```
+    ///
+    /// [rust-lang/rust#89976]: https://github.com/rust-lang/rust/issues/89976
+    pub fn into_fn<A, R>(self) -> impl Fn(A) -> R + Send
+    where
+        F: Fn(A) -> R,
+    {
+        // capture the whole wrapper, not just its `inner` field, to keep it `Send`
+        let this = self;
+        move |a| {
+            let this = &this;
+            (this.inner)(a)
+        }
+    }
+
+    /// Turns this wrapper into a single-argument closure that's still unconditionally
+    /// `Send`, for passing to combinators expecting an `FnMut(A) -> R`.
+    ///
+    /// # Examples
+    ///
+    /// Like for [`into_fn`](Self::into_fn), a `map` closure that borrows from a stream
+    /// of futures over borrowed items doesn't compile inside `tokio::spawn` on its own:
+    ///
+    /// ```compile_fail
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// use futures::future::FutureExt as _;
+    /// use futures::stream::{self, StreamExt as _};
+    ///
+    /// let handle = tokio::spawn(async {
+    ///     let v = vec![1, 2, 3];
+    ///     let mut doubled = stream::iter(&v).map(|&x| async move { x * 2 }).buffer_unordered(2);
+    ///     let pull = move |_: usize| doubled.next().now_or_never().flatten();
+    ///     stream::iter(0..3).map(pull).collect::<Vec<_>>().await
+    /// });
+    /// assert_eq!(handle.await.unwrap(), [Some(2), Some(4), Some(6)]);
+    /// # }
+    /// ```
+    ///
+    /// but does with the closure wrapped:
+    ///
+    /// ```
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// use always_send::FnExt as _;
+    /// use futures::future::FutureExt as _;
+    /// use futures::stream::{self, StreamExt as _};
+    ///
+    /// let handle = tokio::spawn(async {
+    ///     let v = vec![1, 2, 3];
+    ///     let mut doubled = stream::iter(&v).map(|&x| async move { x * 2 }).buffer_unordered(2);
+    ///     let pull = move |_: usize| doubled.next().now_or_never().flatten();
+    ///     let pull = pull.always_send_fn().into_fn_mut();
+    ///     stream::iter(0..3).map(pull).collect::<Vec<_>>().await
+    /// });
+    /// assert_eq!(handle.await.unwrap(), [Some(2), Some(4), Some(6)]);
+    /// # }
+    /// ```
+    ///
+    /// Note that this does *not* help when the futures *returned* by the closure
+    /// are what the compiler struggles with, e.g. when the stream yields
+    /// borrowed items into `buffer_unordered` itself:
+    ///
+    /// ```compile_fail,E0308
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// use always_send::FnExt as _;
+    /// use futures::stream::{self, StreamExt as _};
+    ///
+    /// let handle = tokio::spawn(async {
+    ///     let v = vec![1, 2, 3];
+    ///     let items: Vec<i32> = stream::iter(&v)
+    ///         .map((|&x: &i32| async move { x }).always_send_fn().into_fn_mut())
+    ///         .buffer_unordered(2)
+    ///         .collect()
+    ///         .await;
+    ///     items.len()
+    /// });
+    /// assert_eq!(handle.await.unwrap(), 3);
+    /// # }
+    /// ```
+    ///
+    /// Wrap the resulting future (or stream) with [`always_send!`](crate::always_send!)
+    /// instead, as shown there.
+    pub fn into_fn_mut<A, R>(self) -> impl FnMut(A) -> R + Send
+    where
+        F: FnMut(A) -> R,
+    {
+        // capture the whole wrapper, not just its `inner` field, to keep it `Send`
+        let mut this = self;
+        move |a| {
+            let this = &mut this;
+            (this.inner)(a)
+        }
+    }
+
+    /// Turns this wrapper into a single-argument closure that's still unconditionally
+    /// `Send`, for passing to combinators expecting an `FnOnce(A) -> R`.
+    ///
+    /// # Examples
+    ///
+    /// The same kind of closure as for [`into_fn`](Self::into_fn), passed to
+    /// `FutureExt::map` this time:
+    ///
+    /// ```compile_fail
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// use futures::future::{self, FutureExt as _};
+    /// use futures::stream::{self, Stream, StreamExt as _};
+    ///
+    /// let handle = tokio::spawn(async {
+    ///     let v = vec![1, 2, 3];
+    ///     let doubled = stream::iter(&v).map(|&x| async move { x * 2 }).buffer_unordered(2);
+    ///     let cap = move |n: usize| n.min(doubled.size_hint().0);
+    ///     future::ready(5).map(cap).await
+    /// });
+    /// assert_eq!(handle.await.unwrap(), 3);
+    /// # }
+    /// ```
+    ///
+    /// ```
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// use always_send::FnExt as _;
+    /// use futures::future::{self, FutureExt as _};
+    /// use futures::stream::{self, Stream, StreamExt as _};
+    ///
+    /// let handle = tokio::spawn(async {
+    ///     let v = vec![1, 2, 3];
+    ///     let doubled = stream::iter(&v).map(|&x| async move { x * 2 }).buffer_unordered(2);
+    ///     let cap = move |n: usize| n.min(doubled.size_hint().0);
+    ///     let cap = cap.always_send_fn().into_fn_once();
+    ///     future::ready(5).map(cap).await
+    /// });
+    /// assert_eq!(handle.await.unwrap(), 3);
+    /// # }
+    /// ```
+    pub fn into_fn_once<A, R>(self) -> impl FnOnce(A) -> R + Send
+    where
+        F: FnOnce(A) -> R,
+    {
+        // capture the whole wrapper, not just its `inner` field, to keep it `Send`
+        let this = self;
+        move |a| {
+            let this = this;
+            (this.inner)(a)
+        }
+    }
+}
+
+/// Closures callable by value with the argument tuple `Args`,
+/// see [`AlwaysSendFn::call_once`].
+///
+/// This is implemented for all `FnOnce` closures with up to 4 arguments.
+pub trait CallOnce<Args> {
+    /// The return type of the closure.
+    type Output;
+
+    /// Calls the closure with the arguments `args`.
+    fn call_once(self, args: Args) -> Self::Output;
+}
+
+/// Closures callable by mutable reference with the argument tuple `Args`,
+/// see [`AlwaysSendFn::call_mut`].
+///
+/// This is implemented for all `FnMut` closures with up to 4 arguments.
+pub trait CallMut<Args>: CallOnce<Args> {
+    /// Calls the closure with the arguments `args`.
+    fn call_mut(&mut self, args: Args) -> Self::Output;
+}
+
+/// Closures callable by shared reference with the argument tuple `Args`,
+/// see [`AlwaysSendFn::call`].
+///
+/// This is implemented for all `Fn` closures with up to 4 arguments.
+pub trait Call<Args>: CallMut<Args> {
+    /// Calls the closure with the arguments `args`.
+    fn call(&self, args: Args) -> Self::Output;
+}
+
+macro_rules! impl_call {
+    ($($arg:ident),*) => {
+        impl<F: FnOnce($($arg),*) -> R, R, $($arg),*> CallOnce<($($arg,)*)> for F {
+            type Output = R;
+
+            #[allow(non_snake_case)]
+            fn call_once(self, ($($arg,)*): ($($arg,)*)) -> R {
+                self($($arg),*)
+            }
+        }
+
+        impl<F: FnMut($($arg),*) -> R, R, $($arg),*> CallMut<($($arg,)*)> for F {
+            #[allow(non_snake_case)]
+            fn call_mut(&mut self, ($($arg,)*): ($($arg,)*)) -> R {
+                self($($arg),*)
+            }
+        }
+
+        impl<F: Fn($($arg),*) -> R, R, $($arg),*> Call<($($arg,)*)> for F {
+            #[allow(non_snake_case)]
+            fn call(&self, ($($arg,)*): ($($arg,)*)) -> R {
+                self($($arg),*)
+            }
+        }
+    };
+}
+
+impl_call!();
+impl_call!(A);
+impl_call!(A, B);
+impl_call!(A, B, C);
+impl_call!(A, B, C, D);
+
+/// Convenience extension trait for easy construction
+/// of the [`AlwaysSendFn`] wrapper for closures
+/// in method chains.
+///
+/// As an extension trait, you can import this without a name, like
+/// ```
+/// use always_send::FnExt as _;
+/// ```
+/// for minimizing the potential for ambiguities.
+///
+/// This is only implemented for closures (and other function-like values)
+/// covered by [`CallOnce`], so other `Send` types don't gain the method:
+///
+/// ```compile_fail,E0599
+/// use always_send::FnExt as _;
+///
+/// let _ = 42_i32.always_send_fn();
+/// ```
+pub trait FnExt<Args>: CallOnce<Args> + Send + Sized {
+    fn always_send_fn(self) -> AlwaysSendFn<Self> {
+        AlwaysSendFn::new(self)
+    }
+}
+
+impl<F: CallOnce<Args> + Send, Args> FnExt<Args> for F {}
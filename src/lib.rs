@@ -16,8 +16,15 @@
 //! [docs.rs]: https://docs.rs/always_send/badge.svg
 //! [unsafe forbidden]: https://img.shields.io/badge/unsafe-forbidden-success.svg
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+// tokio requires `std` anyway, and its `AsyncWrite` uses `std::io::IoSlice`
+#[cfg(any(feature = "std", feature = "tokio"))]
+extern crate std;
+
 mod safe {
     use core::marker::PhantomData;
+    use core::mem::ManuallyDrop;
     use core::pin::Pin;
 
     /// Transparent wrapper type around some [`Send`] contents.
@@ -70,37 +77,126 @@ mod safe {
     /// so that subtyping coercions can not later invalidate the `T: Send` check
     /// from when the wrapped value was constructed.
     #[repr(transparent)]
-    pub struct AlwaysSend<T> {
+    pub struct AlwaysSend<T: ?Sized> {
+        marker: PhantomData<fn() -> *mut T>,
         /// The inner value is publicly accessible, and there is no [`Drop`] implementation
         /// so you can have full access to it.
         ///
-        /// For this reasons, we also don't provides any getter methods, or `.into_inner()`.
+        /// For this reason, we don't provide `.into_inner()`, and getter methods such as
+        /// [`as_inner_ref`][AlwaysSend::as_inner_ref] are merely a convenience for method chains.
         ///
         /// Another (private) field in this struct enforces invariance and prevents construction
         /// other than through methods such as [`AlwaysSend::new`].
         pub inner: T,
-        marker: PhantomData<fn() -> *mut T>,
     }
 
     /// This is the main feature, an implementation of `Send` *without* reqiring `T: Send`.
+    ///
+    /// This holds for any `T` whatsoever
+    /// ```
+    /// use always_send::AlwaysSend;
+    /// use std::rc::Rc;
+    ///
+    /// const _: fn() = || {
+    ///     fn assert_send<T: Send>() {}
+    ///     assert_send::<AlwaysSend<Rc<()>>>();
+    /// };
+    /// ```
+    /// whereas actually constructing a value still requires `T: Send`
+    /// ```compile_fail,E0277
+    /// use always_send::AlwaysSend;
+    /// use std::rc::Rc;
+    ///
+    /// let _ = AlwaysSend::new(Rc::new(()));
+    /// ```
+    /// but a value constructed through [`new_unchecked`][AlwaysSend::new_unchecked]
+    /// is `Send`, too
+    /// ```
+    /// use always_send::AlwaysSend;
+    /// use std::rc::Rc;
+    ///
+    /// fn assert_send<T: Send>(_: &T) {}
+    ///
+    /// // SAFETY: the value never leaves this thread
+    /// let wrapped = unsafe { AlwaysSend::new_unchecked(Rc::new(())) };
+    /// assert_send(&wrapped);
+    /// ```
     // SAFETY: all ways of obtaining an (owned or referenced) actual `AlwaysSend` value
-    // at runtime need to go through the `T: Send`-bound API below, and invariance
+    // at runtime need to go through the `T: Send`-bound API below (or `new_unchecked`,
+    // whose caller takes over the responsibility), and invariance
     // ensures that once it's constructed, it cannot be used to send anything *other*
     // than that same `T`, either.
-    unsafe impl<T> Send for AlwaysSend<T> {}
+    unsafe impl<T: ?Sized> Send for AlwaysSend<T> {}
 
     /// This wrapper offers structural pinning of the [`inner`][AlwaysSend::inner] field.
-    impl<T: Unpin> Unpin for AlwaysSend<T> {}
+    impl<T: ?Sized + Unpin> Unpin for AlwaysSend<T> {}
 
     impl<T: Send> AlwaysSend<T> {
         /// Wraps sendable type in the [`AlwaysSend<T>`] wrapper.
-        pub fn new(inner: T) -> Self {
+        ///
+        /// This is a `const fn`, so it works for initializing constants and statics, too.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use always_send::AlwaysSend;
+        ///
+        /// const ANSWER: AlwaysSend<u32> = AlwaysSend::new(42);
+        /// static GREETING: AlwaysSend<&str> = AlwaysSend::new("hello");
+        ///
+        /// const DOUBLED: u32 = ANSWER.copy_inner() * 2;
+        /// assert_eq!(DOUBLED, 84);
+        /// assert_eq!(GREETING.inner, "hello");
+        /// ```
+        pub const fn new(inner: T) -> Self {
             Self {
+                marker: PhantomData,
                 inner,
+            }
+        }
+    }
+    impl<T> AlwaysSend<T> {
+        /// Wraps any type in the [`AlwaysSend<T>`] wrapper, without checking `T: Send`.
+        ///
+        /// Prefer [`AlwaysSend::new`] whenever `T: Send` can be expressed at all.
+        ///
+        /// # Safety
+        ///
+        /// The resulting `AlwaysSend<T>` is `Send` unconditionally, so the caller
+        /// must ensure that doing so is sound: either `T` actually is `Send`
+        /// (just not provably so here, e.g. for some generic `T`),
+        /// or the `AlwaysSend<T>` (and everything obtained from it, such as references
+        /// to the inner value, or the outputs of a wrapped future) never actually
+        /// crosses to another thread in a way that a non-`Send` `T` couldn't.
+        ///
+        /// Note that this also covers `&mut AlwaysSend<T>` and pinned references,
+        /// which allow swapping in other values of type `T`, so the guarantee must hold
+        /// for the type `T` as a whole, not just for this particular value.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use always_send::AlwaysSend;
+        ///
+        /// fn wrap<T>(value: T) -> AlwaysSend<T> {
+        ///     // SAFETY: only ever called with `T = u32` below, which is `Send`
+        ///     unsafe { AlwaysSend::new_unchecked(value) }
+        /// }
+        ///
+        /// assert_eq!(wrap(1_u32).inner, 1);
+        /// ```
+        pub const unsafe fn new_unchecked(inner: T) -> Self {
+            Self {
                 marker: PhantomData,
+                inner,
             }
         }
+    }
 
+    /// The reference-based constructors also support unsized `T`, such as trait objects,
+    /// since `#[repr(transparent)]` gives `AlwaysSend<T>` the same layout as `T`,
+    /// including the same pointer metadata.
+    impl<T: ?Sized + Send> AlwaysSend<T> {
         /// Wrap as `AlwaysSend` behind a reference.
         ///
         /// To go the other way, from `wrapped: &Always<T>` to `&T`,
@@ -114,6 +210,31 @@ mod safe {
         ///
         /// To go the other way, from `wrapped: &mut Always<T>` to `&mut T`,
         /// just access `&mut wrapped.inner`.
+        ///
+        /// # Examples
+        ///
+        /// With an already type-erased future:
+        ///
+        /// ```
+        /// use always_send::AlwaysSend;
+        /// use std::future::Future;
+        /// use std::pin::{pin, Pin};
+        /// use std::task::{Context, Poll};
+        ///
+        /// let mut fut = async {};
+        /// let erased: &mut (dyn Future<Output = ()> + Send) = &mut fut;
+        /// let address = erased as *const _ as *const ();
+        /// let wrapped: &mut AlwaysSend<dyn Future<Output = ()> + Send> = AlwaysSend::from_mut(erased);
+        /// let back: &mut (dyn Future<Output = ()> + Send) = &mut wrapped.inner;
+        /// assert_eq!(back as *const _ as *const (), address);
+        ///
+        /// // likewise behind `Pin`
+        /// let erased: Pin<&mut (dyn Future<Output = ()> + Send)> = pin!(async {});
+        /// let wrapped: Pin<&mut AlwaysSend<dyn Future<Output = ()> + Send>> =
+        ///     AlwaysSend::from_pin_mut(erased);
+        /// let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+        /// assert_eq!(wrapped.poll(&mut cx), Poll::Ready(()));
+        /// ```
         pub fn from_mut(r: &mut T) -> &mut Self {
             // SAFETY: #[repr(transparent)]
             unsafe { &mut *(r as *mut T as *mut Self) }
@@ -135,7 +256,7 @@ mod safe {
             unsafe { r.map_unchecked_mut(Self::from_mut) }
         }
     }
-    impl<T> AlwaysSend<T> {
+    impl<T: ?Sized> AlwaysSend<T> {
         /// Pinned access to <code>self.[inner][Self::inner]</code>.
         pub fn inner_pin(self: Pin<&Self>) -> Pin<&T> {
             // SAFETY: field is structurally pinned
@@ -148,14 +269,149 @@ mod safe {
             unsafe { self.map_unchecked_mut(|this| &mut this.inner) }
         }
     }
+    /// Cloning only requires `T: Clone`, not `T: Send`.
+    ///
+    /// This, and `Copy`, allow deriving these traits on structs containing `AlwaysSend` fields:
+    /// ```
+    /// use always_send::AlwaysSend;
+    ///
+    /// #[derive(Debug, Clone, Default, PartialEq)]
+    /// struct Config {
+    ///     name: AlwaysSend<String>,
+    ///     retries: AlwaysSend<u32>,
+    /// }
+    ///
+    /// let config = Config {
+    ///     name: AlwaysSend::new("primary".to_owned()),
+    ///     ..Config::default()
+    /// };
+    /// let copy = config.clone();
+    /// assert_eq!(copy, config);
+    /// assert_eq!(format!("{copy:?}"), r#"Config { name: "primary", retries: 0 }"#);
+    /// ```
+    impl<T: Clone> Clone for AlwaysSend<T> {
+        fn clone(&self) -> Self {
+            // no `T: Send` bound needed: `self` already proves it was checked on construction,
+            // and the clone has the very same type `T`, so it's sendable just the same
+            Self {
+                marker: PhantomData,
+                inner: self.inner.clone(),
+            }
+        }
+
+        fn clone_from(&mut self, source: &Self) {
+            self.inner.clone_from(&source.inner);
+        }
+    }
+
+    impl<T> AlwaysSend<T> {
+        /// Wraps the inner value in [`ManuallyDrop`], so that it won't be dropped
+        /// unless explicitly requested with [`drop_inner`](AlwaysSend::drop_inner).
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use always_send::AlwaysSend;
+        /// use std::sync::atomic::{AtomicUsize, Ordering};
+        ///
+        /// static DROPS: AtomicUsize = AtomicUsize::new(0);
+        /// struct CountDrops;
+        /// impl Drop for CountDrops {
+        ///     fn drop(&mut self) {
+        ///         DROPS.fetch_add(1, Ordering::SeqCst);
+        ///     }
+        /// }
+        ///
+        /// let mut wrapped = AlwaysSend::new(CountDrops).into_manually_drop();
+        /// let wrapped = std::thread::spawn(move || {
+        ///     // SAFETY: the inner value isn't used again afterwards
+        ///     unsafe { wrapped.drop_inner() };
+        ///     wrapped
+        /// })
+        /// .join()
+        /// .unwrap();
+        /// assert_eq!(DROPS.load(Ordering::SeqCst), 1);
+        /// drop(wrapped);
+        /// assert_eq!(DROPS.load(Ordering::SeqCst), 1);
+        ///
+        /// drop(AlwaysSend::new(CountDrops).into_manually_drop());
+        /// assert_eq!(DROPS.load(Ordering::SeqCst), 1);
+        /// ```
+        pub fn into_manually_drop(self) -> AlwaysSend<ManuallyDrop<T>> {
+            // no `T: Send` bound needed: `self` was already checked on construction,
+            // and `ManuallyDrop<T>` is `Send` exactly if `T` is
+            AlwaysSend {
+                marker: PhantomData,
+                inner: ManuallyDrop::new(self.inner),
+            }
+        }
+
+        /// Wraps the inner value in [`Reverse`](core::cmp::Reverse), reversing its order.
+        ///
+        /// Since the comparison traits of `AlwaysSend` forward to the inner value,
+        /// this turns e.g. a max-heap of wrapped values into a min-heap.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use always_send::AlwaysSend;
+        /// use std::cmp::Reverse;
+        /// use std::collections::BinaryHeap;
+        ///
+        /// let mut heap: BinaryHeap<AlwaysSend<Reverse<i32>>> = BinaryHeap::new();
+        /// for x in [3, 1, 4, 1, 5] {
+        ///     heap.push(AlwaysSend::new(x).reversed());
+        /// }
+        /// let popped = std::iter::from_fn(|| heap.pop()).map(|x| x.inner.0);
+        /// assert_eq!(popped.collect::<Vec<_>>(), [1, 1, 3, 4, 5]);
+        /// ```
+        pub fn reversed(self) -> AlwaysSend<core::cmp::Reverse<T>>
+        where
+            T: Ord,
+        {
+            // no `T: Send` bound needed, for the same reasons as in `into_manually_drop`
+            AlwaysSend {
+                marker: PhantomData,
+                inner: core::cmp::Reverse(self.inner),
+            }
+        }
+    }
 }
 pub use safe::AlwaysSend;
 
+mod covariant;
+pub use covariant::AlwaysSendCovariant;
+
+mod function;
+pub use function::{AlwaysSendFn, Call, CallMut, CallOnce, FnExt};
+
+mod pinned;
+pub use pinned::AlwaysSendPinned;
+
+mod sync;
+pub use sync::{AlwaysSendSync, AlwaysSync};
+
 // the below impls need no access to the implementation details, so
 // we lifted them outside of the module
 use core::future::Future;
 use core::pin::Pin;
 
+/// This blanket implementation also covers collections, such as arrays and `Vec`s,
+/// so no dedicated `From<[T; N]>` or `From<Vec<T>>` implementations are needed
+/// (nor would they be allowed, as they'd overlap with this one).
+///
+/// # Examples
+///
+/// ```
+/// use always_send::AlwaysSend;
+///
+/// let array: AlwaysSend<[i32; 3]> = [1, 2, 3].into();
+/// assert_eq!(array.inner, [1, 2, 3]);
+///
+/// // `Vec<T>: Send` requires `T: Send`
+/// let vec: AlwaysSend<Vec<String>> = vec!["a".to_owned()].into();
+/// assert_eq!(vec.inner, ["a"]);
+/// ```
 impl<T: Send> From<T> for AlwaysSend<T> {
     /// Wraps sendable type in the [`AlwaysSend<T>`] wrapper,
     /// like [`AlwaysSend::new`].
@@ -164,90 +420,3582 @@ impl<T: Send> From<T> for AlwaysSend<T> {
     }
 }
 
-// Future, straightforward delegation
-impl<F: Future> Future for AlwaysSend<F> {
-    type Output = F::Output;
+impl<T> AlwaysSend<T> {
+    /// Reinterprets the wrapped value as a `U`, without any copying or conversion,
+    /// like [`core::mem::transmute`] would.
+    ///
+    /// The result is wrapped again; this is still checking `U: Send`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the bit pattern of the `T` value
+    /// is a valid value of type `U`, with all the same caveats as for `transmute`.
+    /// `T` and `U` don't need the same alignment, since the value is moved
+    /// and not accessed in place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T` and `U` don't have the same size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use always_send::AlwaysSend;
+    /// use core::mem::{align_of, size_of};
+    ///
+    /// assert_eq!(size_of::<AlwaysSend<[u8; 4]>>(), size_of::<AlwaysSend<u32>>());
+    /// assert!(align_of::<AlwaysSend<[u8; 4]>>() <= align_of::<AlwaysSend<u32>>());
+    ///
+    /// let bytes = AlwaysSend::new([1_u8, 2, 3, 4]);
+    /// // SAFETY: same size, and every bit pattern is a valid `u32`
+    /// let n = unsafe { bytes.cast_inner::<u32>() };
+    /// // the numerical result depends on the endianness of the target
+    /// assert_eq!(n.inner, u32::from_ne_bytes([1, 2, 3, 4]));
+    /// ```
+    pub unsafe fn cast_inner<U: Send>(self) -> AlwaysSend<U> {
+        assert_eq!(
+            core::mem::size_of::<T>(),
+            core::mem::size_of::<U>(),
+            "`cast_inner` between types of different size",
+        );
+        let this = core::mem::ManuallyDrop::new(self);
+        // SAFETY: sizes match, and validity is upheld by the caller;
+        // `transmute_copy` does an unaligned read if necessary,
+        // and the `ManuallyDrop` prevents a double drop
+        AlwaysSend::new(unsafe { core::mem::transmute_copy::<T, U>(&this.inner) })
+    }
 
-    fn poll(
-        self: Pin<&mut Self>,
+    /// Awaits the wrapped future in place, without consuming the wrapper.
+    ///
+    /// This is the same as `(&mut wrapped).await`, and the returned future
+    /// is `Send`, too.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use always_send::AlwaysSend;
+    ///
+    /// let mut fut = AlwaysSend::new(Box::pin(async { 42 }));
+    /// assert_eq!(fut.ready().await, 42);
+    /// # });
+    /// ```
+    pub async fn ready(&mut self) -> T::Output
+    where
+        T: Future + Unpin,
+    {
+        self.await
+    }
+
+    /// Polls the wrapped future up to `n` times in a row, stopping early
+    /// once it is ready.
+    ///
+    /// This is mainly useful for deterministic tests of futures, with a
+    /// fixed "fuel" of polls, without needing any runtime or timer.
+    ///
+    /// With `n == 0`, this returns [`Poll::Pending`](core::task::Poll::Pending)
+    /// without polling the future at all, so no waker is registered. Don't
+    /// forward that result from a `poll` implementation, or it may never be woken.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use always_send::AlwaysSend;
+    /// use std::future::Future;
+    /// use std::pin::{pin, Pin};
+    /// use std::task::{Context, Poll};
+    ///
+    /// // becomes ready on the third poll
+    /// struct ThirdTime(u32);
+    /// impl Future for ThirdTime {
+    ///     type Output = ();
+    ///     fn poll(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<()> {
+    ///         self.0 += 1;
+    ///         if self.0 == 3 { Poll::Ready(()) } else { Poll::Pending }
+    ///     }
+    /// }
+    ///
+    /// let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+    /// let fut = pin!(AlwaysSend::new(ThirdTime(0)));
+    /// assert_eq!(fut.poll_n(&mut cx, 2), Poll::Pending);
+    /// let fut = pin!(AlwaysSend::new(ThirdTime(0)));
+    /// assert_eq!(fut.poll_n(&mut cx, 3), Poll::Ready(()));
+    ///
+    /// // no fuel, no polls
+    /// let mut fut = pin!(AlwaysSend::new(ThirdTime(0)));
+    /// assert_eq!(fut.as_mut().poll_n(&mut cx, 0), Poll::Pending);
+    /// assert_eq!(fut.inner.0, 0);
+    /// ```
+    pub fn poll_n(
+        mut self: Pin<&mut Self>,
         cx: &mut core::task::Context<'_>,
-    ) -> core::task::Poll<Self::Output> {
-        self.inner_pin_mut().poll(cx)
+        n: usize,
+    ) -> core::task::Poll<T::Output>
+    where
+        T: Future,
+    {
+        for _ in 0..n {
+            if let core::task::Poll::Ready(output) = self.as_mut().poll(cx) {
+                return core::task::Poll::Ready(output);
+            }
+        }
+        core::task::Poll::Pending
     }
-}
 
-// stream behind an optional feature, since it's another dependency
+    /// Takes out the inner value, leaving [`T::default()`](Default::default) in its place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use always_send::AlwaysSend;
+    ///
+    /// let mut wrapped = AlwaysSend::new(String::from("hello"));
+    /// assert_eq!(wrapped.take_default(), "hello");
+    /// assert_eq!(wrapped.inner, "");
+    /// ```
+    pub fn take_default(&mut self) -> T
+    where
+        T: Default + Send,
+    {
+        core::mem::take(&mut self.inner)
+    }
 
-#[cfg(feature = "stream")]
-use futures_core::{Stream, FusedStream, FusedFuture};
+    /// Swaps the inner value with the value behind `other`.
+    ///
+    /// Like other methods moving values into the wrapper, such as
+    /// [`replace_pinned`](AlwaysSend::replace_pinned), this requires `T: Send`,
+    /// so the check happens wherever a new value comes in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use always_send::AlwaysSend;
+    ///
+    /// let mut wrapped = AlwaysSend::new(String::from("inside"));
+    /// let mut raw = String::from("outside");
+    /// wrapped.swap_with_raw(&mut raw);
+    /// assert_eq!(wrapped.inner, "outside");
+    /// assert_eq!(raw, "inside");
+    /// ```
+    pub fn swap_with_raw(&mut self, other: &mut T)
+    where
+        T: Send,
+    {
+        core::mem::swap(&mut self.inner, other);
+    }
 
-#[cfg(feature = "stream")]
-#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
-impl<S: Stream> Stream for AlwaysSend<S> {
-    type Item = S::Item;
+    /// Replaces the inner value with the result of `f` applied to it.
+    ///
+    /// While `f` runs, the inner value is already taken out and [`T::default()`](Default::default)
+    /// is left in its place, so if `f` panics, the wrapper stays in that default state.
+    /// This needs no unwinding support, so it works the same under `no_std` and `panic = "abort"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use always_send::AlwaysSend;
+    /// use std::panic::{catch_unwind, AssertUnwindSafe};
+    ///
+    /// let mut wrapped = AlwaysSend::new(vec![1, 2]);
+    /// wrapped.replace_with_or_default(|mut v| {
+    ///     v.push(3);
+    ///     v
+    /// });
+    /// assert_eq!(wrapped.inner, [1, 2, 3]);
+    ///
+    /// let result = catch_unwind(AssertUnwindSafe(|| {
+    ///     wrapped.replace_with_or_default(|_| panic!("oops"));
+    /// }));
+    /// assert!(result.is_err());
+    /// assert_eq!(wrapped.inner, []);
+    /// ```
+    pub fn replace_with_or_default<F: FnOnce(T) -> T>(&mut self, f: F)
+    where
+        T: Default + Send,
+    {
+        let old = core::mem::take(&mut self.inner);
+        self.inner = f(old);
+    }
 
-    fn poll_next(
-        self: Pin<&mut Self>,
-        cx: &mut core::task::Context<'_>,
-    ) -> core::task::Poll<Option<Self::Item>> {
-        self.inner_pin_mut().poll_next(cx)
+    /// Replaces the inner value with the result of the asynchronous transformation `f`.
+    ///
+    /// This is only offered for `T: Unpin`, since the inner value is moved out.
+    /// Like with [`replace_with_or_default`](AlwaysSend::replace_with_or_default),
+    /// [`T::default()`](Default::default) is left in its place while `f` runs; so if the
+    /// returned future is dropped before completion (or `f` panics), the wrapper stays
+    /// in that default state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// use always_send::AlwaysSend;
+    /// use futures::FutureExt as _;
+    /// use std::pin::pin;
+    ///
+    /// let mut wrapped = pin!(AlwaysSend::new(vec![1, 2]));
+    /// wrapped
+    ///     .as_mut()
+    ///     .replace_with_future(|mut v| async move {
+    ///         tokio::task::yield_now().await;
+    ///         v.push(3);
+    ///         v
+    ///     })
+    ///     .await;
+    /// assert_eq!(wrapped.inner, [1, 2, 3]);
+    ///
+    /// // cancelled halfway through
+    /// let replacing = wrapped.as_mut().replace_with_future(|v| async move {
+    ///     futures::future::pending::<()>().await;
+    ///     v
+    /// });
+    /// assert_eq!(replacing.now_or_never(), None);
+    /// assert_eq!(wrapped.inner, []);
+    /// # }
+    /// ```
+    pub async fn replace_with_future<Fut, F>(self: Pin<&mut Self>, f: F)
+    where
+        T: Default + Unpin + Send,
+        F: FnOnce(T) -> Fut,
+        Fut: Future<Output = T>,
+    {
+        let this = self.get_mut();
+        let old = core::mem::take(&mut this.inner);
+        this.inner = f(old).await;
     }
 
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        self.inner.size_hint()
+    /// Like [`AlwaysSend::new`], but returning a `Result` that can never be an error.
+    ///
+    /// This is for uniformity with fallible constructors in generic code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use always_send::AlwaysSend;
+    /// use std::convert::Infallible;
+    ///
+    /// fn build() -> Result<AlwaysSend<u32>, Infallible> {
+    ///     let wrapped = AlwaysSend::try_new(42)?;
+    ///     Ok(wrapped)
+    /// }
+    /// assert_eq!(build().unwrap().inner, 42);
+    /// ```
+    pub fn try_new(inner: T) -> Result<Self, core::convert::Infallible>
+    where
+        T: Send,
+    {
+        Ok(AlwaysSend::new(inner))
     }
-}
 
-#[cfg(feature = "stream")]
-#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
-impl<S: FusedStream> FusedStream for AlwaysSend<S> {
-    fn is_terminated(&self) -> bool {
-        self.inner.is_terminated()
+    /// Replaces the inner value behind a pinned reference, returning the old one.
+    ///
+    /// This is only possible for `T: Unpin`, since pinning otherwise forbids
+    /// moving the inner value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use always_send::AlwaysSend;
+    /// use futures::future::{BoxFuture, FutureExt as _};
+    /// use std::pin::pin;
+    ///
+    /// let mut slot = pin!(AlwaysSend::new(async { 1 }.boxed()));
+    /// assert_eq!(slot.as_mut().await, 1);
+    /// let _old: BoxFuture<'_, i32> = slot.as_mut().replace_pinned(async { 2 }.boxed());
+    /// assert_eq!(slot.await, 2);
+    /// # });
+    /// ```
+    pub fn replace_pinned(self: Pin<&mut Self>, value: T) -> T
+    where
+        T: Unpin + Send,
+    {
+        core::mem::replace(&mut self.get_mut().inner, value)
     }
-}
 
-#[cfg(feature = "stream")]
-#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
-impl<F: FusedFuture> FusedFuture for AlwaysSend<F> {
-    fn is_terminated(&self) -> bool {
-        self.inner.is_terminated()
+    /// Alias for [`Clone::clone`], for method chains starting from a borrowed wrapper.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use always_send::AlwaysSend;
+    ///
+    /// let original = AlwaysSend::new(String::from("hello"));
+    /// let borrowed: &AlwaysSend<String> = &original;
+    /// let owned: AlwaysSend<String> = borrowed.cloned();
+    /// assert_eq!(owned, borrowed.clone());
+    /// ```
+    pub fn cloned(&self) -> AlwaysSend<T>
+    where
+        T: Clone,
+    {
+        self.clone()
     }
-}
 
-/// Convenience extension trait for easy construction
-/// of the [`AlwaysSend`] wrapper for futures
-/// in method chains.
-///
-/// As an extension trait, you can import this without a name, like
-/// ```
-/// use always_send::FutureExt as _;
-/// ```
-/// for minimizing the potential for ambiguities.
-pub trait FutureExt: Future + Send + Sized {
-    fn always_send(self) -> AlwaysSend<Self> {
-        AlwaysSend::new(self)
+    /// Transforms the inner value with `f`, wrapping the result again.
+    ///
+    /// This checks `B: Send` here, where `f` is applied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use always_send::AlwaysSend;
+    ///
+    /// let len = AlwaysSend::new(String::from("hello")).map_inner(|s| s.len());
+    /// assert_eq!(len.inner, 5);
+    /// ```
+    pub fn map_inner<B: Send, F: FnOnce(T) -> B>(self, f: F) -> AlwaysSend<B> {
+        AlwaysSend::new(f(self.inner))
     }
-}
 
-impl<F: Future + Send> FutureExt for F {}
+    /// Transitions the inner value to another one with `f`, wrapping the result again.
+    ///
+    /// This is the same as [`map_inner`](AlwaysSend::map_inner), named for the
+    /// common case of moving a wrapped enum from one variant (or enum type) to the next,
+    /// e.g. in a state machine.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use always_send::AlwaysSend;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum Connection {
+    ///     Idle,
+    ///     Connecting { attempt: u32 },
+    ///     Connected(String),
+    /// }
+    ///
+    /// let state = AlwaysSend::new(Connection::Idle);
+    /// let state = state.map_variant(|s| match s {
+    ///     Connection::Idle => Connection::Connecting { attempt: 1 },
+    ///     other => other,
+    /// });
+    /// assert_eq!(state.inner, Connection::Connecting { attempt: 1 });
+    ///
+    /// let state = state.map_variant(|s| match s {
+    ///     Connection::Connecting { attempt } => Connection::Connected(format!("after {attempt}")),
+    ///     other => other,
+    /// });
+    /// assert_eq!(state.inner, Connection::Connected("after 1".to_owned()));
+    ///
+    /// // moving to a different type works, too
+    /// let connected: AlwaysSend<bool> =
+    ///     state.map_variant(|s| matches!(s, Connection::Connected(_)));
+    /// assert!(connected.inner);
+    /// ```
+    ///
+    /// The trait implementations of `AlwaysSend` forward to the inner value,
+    /// so wrapped enums (or bitflags-style newtypes) behave just like unwrapped ones:
+    ///
+    /// ```
+    /// use always_send::AlwaysSend;
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::{Hash, Hasher};
+    ///
+    /// #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+    /// enum Level {
+    ///     #[default]
+    ///     Low,
+    ///     Mid(u8),
+    ///     High { boost: bool },
+    /// }
+    ///
+    /// #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    /// struct Flags(u8);
+    ///
+    /// fn hash(value: &impl Hash) -> u64 {
+    ///     let mut hasher = DefaultHasher::new();
+    ///     value.hash(&mut hasher);
+    ///     hasher.finish()
+    /// }
+    ///
+    /// let levels = [Level::Low, Level::Mid(0), Level::Mid(7), Level::High { boost: true }];
+    /// for a in levels {
+    ///     let wrapped = AlwaysSend::new(a);
+    ///     assert_eq!(format!("{wrapped:?}"), format!("{a:?}"));
+    ///     assert_eq!(format!("{wrapped:#?}"), format!("{a:#?}"));
+    ///     assert_eq!(wrapped.clone().inner, a);
+    ///     assert_eq!(hash(&wrapped), hash(&a));
+    ///     for b in levels {
+    ///         let other = AlwaysSend::new(b);
+    ///         assert_eq!(wrapped == other, a == b);
+    ///         assert_eq!(wrapped.partial_cmp(&other), a.partial_cmp(&b));
+    ///         assert_eq!(wrapped.cmp(&other), a.cmp(&b));
+    ///     }
+    /// }
+    /// assert_eq!(AlwaysSend::<Level>::default().inner, Level::default());
+    ///
+    /// let flags = AlwaysSend::new(Flags(0b101));
+    /// let toggled = flags.map_variant(|Flags(bits)| Flags(bits ^ 0b011));
+    /// assert_eq!(toggled, AlwaysSend::new(Flags(0b110)));
+    /// assert_eq!(format!("{toggled:?}"), "Flags(6)");
+    /// assert_eq!(hash(&toggled), hash(&Flags(0b110)));
+    /// // `Copy` is forwarded, too
+    /// let copy = toggled;
+    /// assert_eq!(copy, toggled);
+    /// ```
+    pub fn map_variant<U: Send, F: FnOnce(T) -> U>(self, f: F) -> AlwaysSend<U> {
+        self.map_inner(f)
+    }
 
-#[cfg(feature = "stream")]
-#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
-/// Convenience extension trait for easy construction
-/// of the [`AlwaysSend`] wrapper for streams
-/// in method chains.
-///
-/// As an extension trait, you can import this without a name, like
-/// ```
-/// use always_send::StreamExt as _;
-/// ```
-/// for minimizing the potential for ambiguities.
-pub trait StreamExt: Stream + Send + Sized {
-    fn always_send(self) -> AlwaysSend<Self> {
-        AlwaysSend::new(self)
+    /// Shared access to <code>self.[inner][Self::inner]</code>,
+    /// for method chains.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use always_send::AlwaysSend;
+    ///
+    /// let wrapped = AlwaysSend::new(vec![1, 2, 3]);
+    /// assert_eq!(wrapped.as_inner_ref().len(), 3);
+    /// ```
+    pub fn as_inner_ref(&self) -> &T {
+        &self.inner
     }
-}
 
-#[cfg(feature = "stream")]
-#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
-impl<S: Stream + Send> StreamExt for S {}
+    /// Mutable access to <code>self.[inner][Self::inner]</code>,
+    /// for method chains.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use always_send::AlwaysSend;
+    ///
+    /// let mut wrapped = AlwaysSend::new(vec![1, 2, 3]);
+    /// wrapped.as_inner_mut().push(4);
+    /// assert_eq!(wrapped.inner, [1, 2, 3, 4]);
+    /// ```
+    pub fn as_inner_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Copies out the inner value, usable in `const` contexts, too.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use always_send::AlwaysSend;
+    ///
+    /// const fn doubled(x: &AlwaysSend<u32>) -> u32 {
+    ///     x.copy_inner() * 2
+    /// }
+    ///
+    /// assert_eq!(doubled(&AlwaysSend::new(21)), 42);
+    /// ```
+    pub const fn copy_inner(&self) -> T
+    where
+        T: Copy,
+    {
+        self.inner
+    }
+
+    /// Moves this wrapper into a pinned box.
+    ///
+    /// This is the canonical way from an owned wrapper around a `!Unpin` future
+    /// to something `Unpin` that can be polled by reference; the result is still `Send`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use always_send::FutureExt as _;
+    ///
+    /// fn assert_send<T: Send>(_: &T) {}
+    ///
+    /// let mut fut = async { 42 }.always_send().pin_box_inner();
+    /// assert_send(&fut);
+    /// assert_eq!((&mut fut).await, 42);
+    /// # });
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn pin_box_inner(self) -> Pin<alloc::boxed::Box<Self>> {
+        alloc::boxed::Box::pin(self)
+    }
+
+    /// Moves this wrapper into a pinned box, type-erasing the future.
+    ///
+    /// This produces the same type that the `.boxed()` workaround from the
+    /// `futures` crate would give.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use always_send::FutureExt as _;
+    /// use futures::future::BoxFuture;
+    ///
+    /// let fut: BoxFuture<'_, i32> = async { 42 }.always_send().pin_box_dyn();
+    /// assert_eq!(fut.await, 42);
+    /// # });
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn pin_box_dyn<'a>(
+        self,
+    ) -> Pin<alloc::boxed::Box<dyn Future<Output = T::Output> + Send + 'a>>
+    where
+        T: Future + 'a,
+    {
+        alloc::boxed::Box::pin(self)
+    }
+
+    /// Does nothing, but only compiles if the inner value is `Unpin`.
+    ///
+    /// This documents (and enforces) that the inner value is `Unpin` at a specific
+    /// point in a method chain, e.g. in code that later relies on [`Pin::get_mut`].
+    /// See also [`is_unpin`] for a variant usable in `const` context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use always_send::FutureExt as _;
+    ///
+    /// let _ = std::future::ready(1).always_send().assert_unpin();
+    /// ```
+    ///
+    /// ```compile_fail,E0277
+    /// use always_send::FutureExt as _;
+    ///
+    /// let _ = async {}.always_send().assert_unpin();
+    /// ```
+    #[must_use]
+    pub fn assert_unpin(self) -> Self
+    where
+        T: Unpin,
+    {
+        self
+    }
+
+    /// Runs `f` on pinned mutable access to <code>self.[inner][Self::inner]</code>.
+    ///
+    /// This is just `f(self.inner_pin_mut())`, but can read nicer e.g. in manual `poll` implementations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use always_send::{AlwaysSend, FutureExt as _};
+    /// use std::future::Future;
+    /// use std::pin::Pin;
+    /// use std::task::{Context, Poll};
+    ///
+    /// // counts the polls of its inner future
+    /// struct CountPolls<F> {
+    ///     inner: Pin<Box<AlwaysSend<F>>>,
+    ///     polls: usize,
+    /// }
+    /// impl<F: Future> Future for CountPolls<F> {
+    ///     type Output = (F::Output, usize);
+    ///     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+    ///         self.polls += 1;
+    ///         let output = self.inner.as_mut().with_pinned(|fut| fut.poll(cx));
+    ///         output.map(|output| (output, self.polls))
+    ///     }
+    /// }
+    ///
+    /// let inner = Box::pin(async {
+    ///     futures::pending!(); // requires one more poll
+    ///     42
+    /// }.always_send());
+    /// let mut fut = CountPolls { inner, polls: 0 };
+    /// let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+    /// assert_eq!(Pin::new(&mut fut).poll(&mut cx), Poll::Pending);
+    /// assert_eq!(Pin::new(&mut fut).poll(&mut cx), Poll::Ready((42, 2)));
+    /// ```
+    pub fn with_pinned<R>(self: Pin<&mut Self>, f: impl FnOnce(Pin<&mut T>) -> R) -> R {
+        f(self.inner_pin_mut())
+    }
+
+    /// Alias of [`with_pinned`](Self::with_pinned), for call sites that derive a value
+    /// from the inner state, rather than driving it.
+    ///
+    /// Unlike [`map_inner`](Self::map_inner), this can't change the type `T`: a pinned
+    /// inner value must stay in place until dropped, so it can't be moved out
+    /// to be turned into something else.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use always_send::AlwaysSend;
+    /// use futures::future::{FusedFuture as _, FutureExt as _};
+    /// use std::future::Future as _;
+    /// use std::pin::pin;
+    /// use std::task::Context;
+    ///
+    /// let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+    /// let mut fut = pin!(AlwaysSend::new(async { 1 }.fuse()));
+    /// assert!(!fut.as_mut().map_inner_pinned(|inner| inner.is_terminated()));
+    /// assert!(fut.as_mut().map_inner_pinned(|inner| inner.poll(&mut cx).is_ready()));
+    /// assert!(fut.as_mut().map_inner_pinned(|inner| inner.is_terminated()));
+    /// ```
+    pub fn map_inner_pinned<R>(self: Pin<&mut Self>, f: impl FnOnce(Pin<&mut T>) -> R) -> R {
+        self.with_pinned(f)
+    }
+
+    /// Formats as `AlwaysSend(<inner>)`, unlike the transparent `Debug` implementation
+    /// of `AlwaysSend<T>` itself, which formats just like the inner value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use always_send::AlwaysSend;
+    ///
+    /// let wrapped = AlwaysSend::new(Some(1));
+    /// assert_eq!(format!("{wrapped:?}"), "Some(1)");
+    /// assert_eq!(format!("{:?}", wrapped.debug_wrapped()), "AlwaysSend(Some(1))");
+    /// ```
+    pub fn debug_wrapped(&self) -> impl core::fmt::Debug + '_
+    where
+        T: core::fmt::Debug,
+    {
+        struct DebugWrapped<'a, T>(&'a T);
+        impl<T: core::fmt::Debug> core::fmt::Debug for DebugWrapped<'_, T> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_tuple("AlwaysSend").field(self.0).finish()
+            }
+        }
+        DebugWrapped(&self.inner)
+    }
+}
+
+impl<T> AlwaysSend<core::mem::ManuallyDrop<T>> {
+    /// Drops the inner value in place, like [`ManuallyDrop::drop`](core::mem::ManuallyDrop::drop).
+    ///
+    /// See [`into_manually_drop`](AlwaysSend::into_manually_drop) for an example.
+    ///
+    /// # Safety
+    ///
+    /// Same as for `ManuallyDrop::drop`: afterwards, the inner value must not be used
+    /// anymore, and in particular, this method must not be called more than once.
+    pub unsafe fn drop_inner(&mut self) {
+        // SAFETY: upheld by the caller
+        unsafe { core::mem::ManuallyDrop::drop(&mut self.inner) }
+    }
+}
+
+impl<T, E> AlwaysSend<Result<T, E>> {
+    /// Like [`Result::map_or`] on the wrapped result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use always_send::AlwaysSend;
+    ///
+    /// let ok = AlwaysSend::new(Ok::<_, ()>(2));
+    /// assert_eq!(ok.map_or(0, |x| x * 10), 20);
+    /// let err = AlwaysSend::new(Err::<i32, _>(()));
+    /// assert_eq!(err.map_or(0, |x| x * 10), 0);
+    /// ```
+    pub fn map_or<U, F: FnOnce(T) -> U>(self, default: U, f: F) -> U {
+        self.inner.map_or(default, f)
+    }
+
+    /// Like [`Result::unwrap_or`] on the wrapped result, keeping the result wrapped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use always_send::AlwaysSend;
+    ///
+    /// let ok = AlwaysSend::new(Ok::<_, ()>(2));
+    /// assert_eq!(ok.unwrap_or(0).inner, 2);
+    /// let err = AlwaysSend::new(Err::<i32, _>(()));
+    /// assert_eq!(err.unwrap_or(0).inner, 0);
+    /// ```
+    pub fn unwrap_or(self, default: T) -> AlwaysSend<T>
+    where
+        T: Send,
+    {
+        AlwaysSend::new(self.inner.unwrap_or(default))
+    }
+}
+
+impl<T, E> AlwaysSend<Result<AlwaysSend<T>, E>> {
+    /// Collapses the outer wrapper, keeping the inner one on success.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use always_send::AlwaysSend;
+    ///
+    /// let ok = AlwaysSend::new(Ok::<_, &str>(AlwaysSend::new(1)));
+    /// let inner: AlwaysSend<i32> = ok.flatten_result().unwrap();
+    /// assert_eq!(inner.inner, 1);
+    ///
+    /// let err = AlwaysSend::new(Err::<AlwaysSend<i32>, _>("bad"));
+    /// assert_eq!(err.flatten_result().err(), Some("bad"));
+    /// ```
+    pub fn flatten_result(self) -> Result<AlwaysSend<T>, E> {
+        self.inner
+    }
+}
+
+impl<T> AlwaysSend<Option<T>> {
+    /// Like [`Option::map_or`] on the wrapped option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use always_send::AlwaysSend;
+    ///
+    /// let some = AlwaysSend::new(Some(2));
+    /// assert_eq!(some.map_or(0, |x| x * 10), 20);
+    /// let none = AlwaysSend::new(None::<i32>);
+    /// assert_eq!(none.map_or(0, |x| x * 10), 0);
+    /// ```
+    pub fn map_or<U, F: FnOnce(T) -> U>(self, default: U, f: F) -> U {
+        self.inner.map_or(default, f)
+    }
+
+    /// Like [`Option::unwrap_or`] on the wrapped option, keeping the result wrapped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use always_send::AlwaysSend;
+    ///
+    /// let some = AlwaysSend::new(Some(2));
+    /// assert_eq!(some.unwrap_or(0).inner, 2);
+    /// let none = AlwaysSend::new(None::<i32>);
+    /// assert_eq!(none.unwrap_or(0).inner, 0);
+    /// ```
+    pub fn unwrap_or(self, default: T) -> AlwaysSend<T>
+    where
+        T: Send,
+    {
+        AlwaysSend::new(self.inner.unwrap_or(default))
+    }
+}
+
+impl<'a, T: ?Sized + Sync> AlwaysSend<&'a T> {
+    /// Wraps a shared reference, which is `Send` because `T: Sync`.
+    ///
+    /// Unlike [`AlwaysSend::from_ref`], which gives a `&AlwaysSend<T>`, this
+    /// produces an owned wrapper around the reference itself, e.g. for moving
+    /// a shared borrow into a spawned task.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use always_send::AlwaysSend;
+    /// use std::sync::atomic::{AtomicU32, Ordering};
+    ///
+    /// let counter = AtomicU32::new(0);
+    /// let wrapped = AlwaysSend::wrap_ref(&counter);
+    /// std::thread::scope(|s| {
+    ///     s.spawn(move || {
+    ///         let wrapped = wrapped; // move the whole wrapper
+    ///         wrapped.inner.fetch_add(1, Ordering::Relaxed);
+    ///     });
+    /// });
+    /// assert_eq!(counter.load(Ordering::Relaxed), 1);
+    /// ```
+    pub fn wrap_ref(r: &'a T) -> Self {
+        AlwaysSend::new(r)
+    }
+}
+
+impl<T: PartialEq> PartialEq for AlwaysSend<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+/// Only implemented for `T: Eq`, so e.g. floating-point inners are correctly excluded.
+///
+/// ```
+/// use always_send::AlwaysSend;
+///
+/// fn assert_eq_impl<T: Eq>() {}
+/// assert_eq_impl::<AlwaysSend<i32>>();
+/// ```
+///
+/// ```compile_fail,E0277
+/// use always_send::AlwaysSend;
+///
+/// fn assert_eq_impl<T: Eq>() {}
+/// assert_eq_impl::<AlwaysSend<f32>>();
+/// ```
+impl<T: Eq> Eq for AlwaysSend<T> {}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<T: Send> AlwaysSend<alloc::vec::Vec<T>> {
+    /// Converts the inner `Vec` into a boxed slice, like
+    /// [`Vec::into_boxed_slice`](alloc::vec::Vec::into_boxed_slice).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use always_send::AlwaysSend;
+    ///
+    /// let mut buffer = AlwaysSend::new(Vec::new());
+    /// buffer.inner.extend_from_slice(b"abc");
+    /// let frozen: AlwaysSend<Box<[u8]>> = buffer.into_boxed_slice();
+    /// assert_eq!(&*frozen.inner, b"abc");
+    /// ```
+    pub fn into_boxed_slice(self) -> AlwaysSend<alloc::boxed::Box<[T]>> {
+        self.map_inner(alloc::vec::Vec::into_boxed_slice)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl AlwaysSend<alloc::boxed::Box<dyn core::any::Any + Send>> {
+    /// Attempts to downcast the type-erased inner value to a concrete type `T`.
+    ///
+    /// On failure, the original wrapper is handed back unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use always_send::AlwaysSend;
+    /// use std::any::Any;
+    ///
+    /// let erased = || AlwaysSend::new(Box::new(42_u32) as Box<dyn Any + Send>);
+    /// assert_eq!(erased().downcast::<u32>().ok().unwrap().inner, 42);
+    /// let err = erased().downcast::<String>().err().unwrap();
+    /// assert_eq!(err.inner.downcast_ref::<u32>(), Some(&42));
+    /// ```
+    pub fn downcast<T: core::any::Any + Send>(self) -> Result<AlwaysSend<T>, Self> {
+        match self.inner.downcast::<T>() {
+            Ok(value) => Ok(AlwaysSend::new(*value)),
+            Err(erased) => Err(AlwaysSend::new(erased)),
+        }
+    }
+}
+
+impl<T: Copy> Copy for AlwaysSend<T> {}
+
+/// Wraps [`T::default()`](Default::default), which requires `T: Send`
+/// just like [`AlwaysSend::new`].
+impl<T: Default + Send> Default for AlwaysSend<T> {
+    fn default() -> Self {
+        AlwaysSend::new(T::default())
+    }
+}
+
+/// Compares the [`inner`][AlwaysSend::inner] value against a borrowed `T`.
+///
+/// ```
+/// use always_send::AlwaysSend;
+///
+/// let x = AlwaysSend::new(5);
+/// assert!(x == &5);
+/// assert!(x != &6);
+/// ```
+impl<T: PartialEq> PartialEq<&T> for AlwaysSend<T> {
+    fn eq(&self, other: &&T) -> bool {
+        self.inner == **other
+    }
+}
+
+/// Compares the [`inner`][AlwaysSend::inner] value against a borrowed `T`.
+///
+/// ```
+/// use always_send::AlwaysSend;
+///
+/// let x = AlwaysSend::new(5);
+/// assert!(x < &6);
+/// assert!(x >= &5);
+/// assert_eq!(x.partial_cmp(&&4), Some(core::cmp::Ordering::Greater));
+/// ```
+impl<T: PartialOrd> PartialOrd<&T> for AlwaysSend<T> {
+    fn partial_cmp(&self, other: &&T) -> Option<core::cmp::Ordering> {
+        self.inner.partial_cmp(*other)
+    }
+}
+
+impl<T: PartialOrd> PartialOrd for AlwaysSend<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.inner.partial_cmp(&other.inner)
+    }
+}
+
+impl<T: Ord> Ord for AlwaysSend<T> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.inner.cmp(&other.inner)
+    }
+}
+
+/// Like the comparison traits, this forwards to the [`inner`][AlwaysSend::inner] value,
+/// so wrapping a field doesn't change the behavior of derived implementations.
+///
+/// ```
+/// use always_send::AlwaysSend;
+/// use std::hash::{BuildHasher, RandomState};
+///
+/// #[derive(Hash, PartialEq, Eq, PartialOrd, Ord)]
+/// struct Plain(u32, &'static str);
+/// #[derive(Hash, PartialEq, Eq, PartialOrd, Ord)]
+/// struct Wrapped(AlwaysSend<u32>, &'static str);
+///
+/// let pairs = [(1, "a"), (1, "b"), (2, "a"), (0, "z")];
+/// let state = RandomState::new();
+/// for &(n1, s1) in &pairs {
+///     let plain = Plain(n1, s1);
+///     let wrapped = Wrapped(AlwaysSend::new(n1), s1);
+///     assert_eq!(state.hash_one(&plain), state.hash_one(&wrapped));
+///     for &(n2, s2) in &pairs {
+///         assert_eq!(
+///             plain.cmp(&Plain(n2, s2)),
+///             wrapped.cmp(&Wrapped(AlwaysSend::new(n2), s2)),
+///         );
+///         assert_eq!(
+///             plain == Plain(n2, s2),
+///             wrapped == Wrapped(AlwaysSend::new(n2), s2),
+///         );
+///     }
+/// }
+/// ```
+impl<T: core::hash::Hash> core::hash::Hash for AlwaysSend<T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.inner.hash(state);
+    }
+}
+
+/// Forwards to the [`inner`][AlwaysSend::inner] hasher.
+///
+/// This keeps a stateful custom hasher `Send`, and produces
+/// exactly the same hashes as the unwrapped hasher.
+///
+/// ```
+/// use always_send::AlwaysSend;
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::hash::{Hash, Hasher};
+///
+/// let mut raw = DefaultHasher::new();
+/// let mut wrapped = AlwaysSend::new(DefaultHasher::new());
+/// for hasher in [&mut raw as &mut dyn Hasher, &mut wrapped] {
+///     hasher.write(b"some bytes");
+///     hasher.write_u32(42);
+///     hasher.write_i128(-1);
+///     "a string".hash(&mut &mut *hasher);
+/// }
+/// assert_eq!(raw.finish(), wrapped.finish());
+/// ```
+impl<H: ?Sized + core::hash::Hasher> core::hash::Hasher for AlwaysSend<H> {
+    fn finish(&self) -> u64 {
+        self.inner.finish()
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.inner.write(bytes);
+    }
+
+    fn write_u8(&mut self, i: u8) {
+        self.inner.write_u8(i);
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.inner.write_u16(i);
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.inner.write_u32(i);
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.inner.write_u64(i);
+    }
+
+    fn write_u128(&mut self, i: u128) {
+        self.inner.write_u128(i);
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.inner.write_usize(i);
+    }
+
+    fn write_i8(&mut self, i: i8) {
+        self.inner.write_i8(i);
+    }
+
+    fn write_i16(&mut self, i: i16) {
+        self.inner.write_i16(i);
+    }
+
+    fn write_i32(&mut self, i: i32) {
+        self.inner.write_i32(i);
+    }
+
+    fn write_i64(&mut self, i: i64) {
+        self.inner.write_i64(i);
+    }
+
+    fn write_i128(&mut self, i: i128) {
+        self.inner.write_i128(i);
+    }
+
+    fn write_isize(&mut self, i: isize) {
+        self.inner.write_isize(i);
+    }
+}
+
+/// Borrows the [`inner`][AlwaysSend::inner] value.
+///
+/// This allows e.g. querying a `HashMap<AlwaysSend<K>, V>` with a plain `&K`.
+/// As required for `Borrow`, the `Hash`, `Eq` and `Ord` implementations
+/// of `AlwaysSend<T>` agree with those of `T`, since they simply forward
+/// to the inner value.
+///
+/// ```
+/// use always_send::AlwaysSend;
+/// use std::collections::HashMap;
+///
+/// let mut map = HashMap::new();
+/// map.insert(AlwaysSend::new(42_u32), "value");
+/// assert_eq!(map.get(&42), Some(&"value"));
+/// ```
+impl<T> core::borrow::Borrow<T> for AlwaysSend<T> {
+    fn borrow(&self) -> &T {
+        &self.inner
+    }
+}
+
+/// Formats transparently, just like the [`inner`][AlwaysSend::inner] value.
+///
+/// For `AlwaysSend(<inner>)` output, see [`AlwaysSend::debug_wrapped`].
+impl<T: core::fmt::Debug> core::fmt::Debug for AlwaysSend<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+/// Straightforward delegation to the inner future.
+///
+/// This also covers boxed trait-object futures; there's no need for a dedicated implementation
+/// (which would overlap with this one).
+///
+/// # Examples
+///
+/// ```
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// use always_send::AlwaysSend;
+/// use std::future::Future;
+/// use std::pin::Pin;
+///
+/// let boxed: Pin<Box<dyn Future<Output = i32> + Send>> = Box::pin(async { 42 });
+/// let wrapped = AlwaysSend::new(boxed);
+/// assert_eq!(tokio::spawn(wrapped).await.unwrap(), 42);
+/// # }
+/// ```
+impl<F: ?Sized + Future> Future for AlwaysSend<F> {
+    type Output = F::Output;
+
+    fn poll(
+        self: Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<Self::Output> {
+        self.inner_pin_mut().poll(cx)
+    }
+}
+
+// Iterator, straightforward delegation, too
+impl<I: Iterator> Iterator for AlwaysSend<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.inner.nth(n)
+    }
+
+    fn fold<B, F>(self, init: B, f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        self.inner.fold(init, f)
+    }
+}
+
+impl<I: DoubleEndedIterator> DoubleEndedIterator for AlwaysSend<I> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for AlwaysSend<I> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl<I: core::iter::FusedIterator> core::iter::FusedIterator for AlwaysSend<I> {}
+
+// stream behind an optional feature, since it's another dependency
+
+#[cfg(feature = "stream")]
+use futures_core::{Stream, FusedStream, FusedFuture};
+
+#[cfg(feature = "stream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+pub mod stream;
+
+/// Through this implementation, wrapped streams of `Result`s are also
+/// [`TryStream`](futures_core::TryStream)s (and wrapped futures of `Result`s
+/// are [`TryFuture`](futures_core::TryFuture)s), by the blanket implementations
+/// of `futures-core`, with the same `Ok` and `Error` types.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use always_send::StreamExt as _;
+/// use futures::stream::{self, TryStreamExt as _};
+///
+/// let all_ok = stream::iter([Ok::<_, &str>(1), Ok(2), Ok(3)]).always_send();
+/// assert_eq!(all_ok.try_collect::<Vec<_>>().await, Ok(vec![1, 2, 3]));
+///
+/// let mut seen = Vec::new();
+/// let early_err = stream::iter([Ok(1), Err("bad"), Ok(3)])
+///     .always_send()
+///     .try_for_each(|x| {
+///         seen.push(x);
+///         async { Ok(()) }
+///     })
+///     .await;
+/// assert_eq!(early_err, Err("bad"));
+/// assert_eq!(seen, [1]);
+/// # });
+/// ```
+#[cfg(feature = "stream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+impl<S: ?Sized + Stream> Stream for AlwaysSend<S> {
+    type Item = S::Item;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<Option<Self::Item>> {
+        self.inner_pin_mut().poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(feature = "stream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+impl<S: ?Sized + FusedStream> FusedStream for AlwaysSend<S> {
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+#[cfg(feature = "stream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+impl<F: ?Sized + FusedFuture> FusedFuture for AlwaysSend<F> {
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+#[cfg(feature = "stream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+impl<T> AlwaysSend<T> {
+    /// Extends the inner collection with all items of `stream`.
+    ///
+    /// The returned future borrows `self` mutably, which is `Send` regardless of `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use always_send::{AlwaysSend, StreamExt as _};
+    /// use futures::stream;
+    ///
+    /// let mut numbers = AlwaysSend::new(vec![0_u32]);
+    /// numbers
+    ///     .extend_from_stream_always_send(stream::iter(1..=3).always_send())
+    ///     .await;
+    /// assert_eq!(numbers.inner, [0, 1, 2, 3]);
+    /// # });
+    /// ```
+    pub async fn extend_from_stream_always_send<S, A>(&mut self, stream: S)
+    where
+        S: Stream<Item = A> + Send,
+        T: Extend<A>,
+    {
+        let mut stream = core::pin::pin!(stream);
+        while let Some(item) = futures_util::StreamExt::next(&mut stream).await {
+            self.inner.extend(core::iter::once(item));
+        }
+    }
+}
+
+// serde behind an optional feature, too
+
+/// Serializes transparently, just like the [`inner`][AlwaysSend::inner] value.
+///
+/// # Examples
+///
+/// ```
+/// use always_send::AlwaysSend;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct State {
+///     name: AlwaysSend<String>,
+///     counts: AlwaysSend<Vec<u32>>,
+/// }
+///
+/// let state = State {
+///     name: AlwaysSend::new("primary".to_owned()),
+///     counts: AlwaysSend::new(vec![1, 2]),
+/// };
+/// let json = serde_json::to_string(&state).unwrap();
+/// assert_eq!(json, r#"{"name":"primary","counts":[1,2]}"#);
+/// assert_eq!(serde_json::from_str::<State>(&json).unwrap(), state);
+/// ```
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<T: serde::Serialize> serde::Serialize for AlwaysSend<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.inner.serialize(serializer)
+    }
+}
+
+/// Deserializes transparently, just like the [`inner`][AlwaysSend::inner] value.
+///
+/// This constructs a fresh value, so it requires `T: Send` just like [`AlwaysSend::new`].
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de, T: serde::Deserialize<'de> + Send> serde::Deserialize<'de> for AlwaysSend<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(AlwaysSend::new)
+    }
+}
+
+// async I/O traits from `futures-io` behind an optional feature, too;
+// note that this feature enables the `std` feature, since `futures-io` only defines
+// these traits with its own `std` feature enabled
+
+/// Reading is delegated to the inner reader, including
+/// [`poll_read_vectored`](futures_io::AsyncRead::poll_read_vectored).
+///
+/// Like all `futures-io` support, this requires `std`, even though
+/// this crate is otherwise `no_std`.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use always_send::AlwaysSend;
+/// use futures::io::{AsyncReadExt as _, Cursor, IoSliceMut};
+///
+/// let mut reader = AlwaysSend::new(Cursor::new(b"abcd"));
+/// let (mut a, mut b) = ([0; 2], [0; 2]);
+/// let mut bufs = [IoSliceMut::new(&mut a), IoSliceMut::new(&mut b)];
+/// // the default implementation would only fill the first buffer
+/// assert_eq!(reader.read_vectored(&mut bufs).await.unwrap(), 4);
+/// assert_eq!((a, b), (*b"ab", *b"cd"));
+/// # });
+/// ```
+#[cfg(feature = "futures-io")]
+#[cfg_attr(docsrs, doc(cfg(feature = "futures-io")))]
+impl<R: ?Sized + futures_io::AsyncRead> futures_io::AsyncRead for AlwaysSend<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+        buf: &mut [u8],
+    ) -> core::task::Poll<futures_io::Result<usize>> {
+        self.inner_pin_mut().poll_read(cx, buf)
+    }
+
+    fn poll_read_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+        bufs: &mut [futures_io::IoSliceMut<'_>],
+    ) -> core::task::Poll<futures_io::Result<usize>> {
+        self.inner_pin_mut().poll_read_vectored(cx, bufs)
+    }
+}
+
+/// Writing is delegated to the inner writer, including
+/// [`poll_write_vectored`](futures_io::AsyncWrite::poll_write_vectored).
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use always_send::AlwaysSend;
+/// use futures::io::{AsyncWriteExt as _, Cursor, IoSlice};
+///
+/// let mut writer = AlwaysSend::new(Cursor::new(Vec::new()));
+/// let bufs = [IoSlice::new(b"ab"), IoSlice::new(b"cd")];
+/// // the default implementation would only write the first buffer
+/// assert_eq!(writer.write_vectored(&bufs).await.unwrap(), 4);
+/// writer.close().await.unwrap();
+/// assert_eq!(writer.inner.into_inner(), b"abcd");
+/// # });
+/// ```
+#[cfg(feature = "futures-io")]
+#[cfg_attr(docsrs, doc(cfg(feature = "futures-io")))]
+impl<W: ?Sized + futures_io::AsyncWrite> futures_io::AsyncWrite for AlwaysSend<W> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+        buf: &[u8],
+    ) -> core::task::Poll<futures_io::Result<usize>> {
+        self.inner_pin_mut().poll_write(cx, buf)
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+        bufs: &[futures_io::IoSlice<'_>],
+    ) -> core::task::Poll<futures_io::Result<usize>> {
+        self.inner_pin_mut().poll_write_vectored(cx, bufs)
+    }
+
+    fn poll_flush(
+        self: Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<futures_io::Result<()>> {
+        self.inner_pin_mut().poll_flush(cx)
+    }
+
+    fn poll_close(
+        self: Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<futures_io::Result<()>> {
+        self.inner_pin_mut().poll_close(cx)
+    }
+}
+
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use always_send::AlwaysSend;
+/// use futures::io::{AsyncBufReadExt as _, AsyncSeekExt as _, Cursor, SeekFrom};
+///
+/// let mut reader = AlwaysSend::new(Cursor::new("first\nsecond\n"));
+/// reader.seek(SeekFrom::Start(6)).await.unwrap();
+/// let mut line = String::new();
+/// reader.read_line(&mut line).await.unwrap();
+/// assert_eq!(line, "second\n");
+/// # });
+/// ```
+#[cfg(feature = "futures-io")]
+#[cfg_attr(docsrs, doc(cfg(feature = "futures-io")))]
+impl<R: ?Sized + futures_io::AsyncBufRead> futures_io::AsyncBufRead for AlwaysSend<R> {
+    fn poll_fill_buf(
+        self: Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<futures_io::Result<&[u8]>> {
+        self.inner_pin_mut().poll_fill_buf(cx)
+    }
+
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        self.inner_pin_mut().consume(amt)
+    }
+}
+
+#[cfg(feature = "futures-io")]
+#[cfg_attr(docsrs, doc(cfg(feature = "futures-io")))]
+impl<S: ?Sized + futures_io::AsyncSeek> futures_io::AsyncSeek for AlwaysSend<S> {
+    fn poll_seek(
+        self: Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+        pos: futures_io::SeekFrom,
+    ) -> core::task::Poll<futures_io::Result<u64>> {
+        self.inner_pin_mut().poll_seek(cx, pos)
+    }
+}
+
+// the same for tokio's async I/O traits, independently of `futures-io`
+
+/// Reading is delegated to the inner reader.
+///
+/// # Examples
+///
+/// ```
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// use always_send::AlwaysSend;
+/// use tokio::io::{AsyncReadExt as _, AsyncWriteExt as _};
+///
+/// let (a, b) = tokio::io::duplex(64);
+/// let mut a = AlwaysSend::new(a);
+/// let mut b = AlwaysSend::new(b);
+///
+/// let task = tokio::spawn(async move {
+///     a.write_all(b"ping").await.unwrap();
+///     a.shutdown().await.unwrap();
+/// });
+/// let mut received = String::new();
+/// b.read_to_string(&mut received).await.unwrap();
+/// assert_eq!(received, "ping");
+/// task.await.unwrap();
+/// # }
+/// ```
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+impl<R: ?Sized + tokio::io::AsyncRead> tokio::io::AsyncRead for AlwaysSend<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+        buf: &mut tokio::io::ReadBuf<'_>,
+    ) -> core::task::Poll<tokio::io::Result<()>> {
+        self.inner_pin_mut().poll_read(cx, buf)
+    }
+}
+
+/// Writing is delegated to the inner writer, including
+/// [`poll_write_vectored`](tokio::io::AsyncWrite::poll_write_vectored)
+/// and [`is_write_vectored`](tokio::io::AsyncWrite::is_write_vectored).
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+impl<W: ?Sized + tokio::io::AsyncWrite> tokio::io::AsyncWrite for AlwaysSend<W> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+        buf: &[u8],
+    ) -> core::task::Poll<tokio::io::Result<usize>> {
+        self.inner_pin_mut().poll_write(cx, buf)
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+        bufs: &[std::io::IoSlice<'_>],
+    ) -> core::task::Poll<tokio::io::Result<usize>> {
+        self.inner_pin_mut().poll_write_vectored(cx, bufs)
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        self.inner.is_write_vectored()
+    }
+
+    fn poll_flush(
+        self: Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<tokio::io::Result<()>> {
+        self.inner_pin_mut().poll_flush(cx)
+    }
+
+    fn poll_shutdown(
+        self: Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<tokio::io::Result<()>> {
+        self.inner_pin_mut().poll_shutdown(cx)
+    }
+}
+
+/// Sinks are delegated, too, with the same `Error` type. This doesn't require `Item: Send`;
+/// like everywhere else, only the `S: Send` check on construction matters.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use always_send::AlwaysSend;
+/// use futures::channel::mpsc;
+/// use futures::sink::SinkExt as _;
+/// use futures::stream::StreamExt as _;
+///
+/// let (sender, receiver) = mpsc::channel(4);
+/// let mut sender = AlwaysSend::new(sender);
+/// sender.send(1).await.unwrap();
+/// sender.send(2).await.unwrap();
+/// sender.close().await.unwrap();
+/// assert_eq!(receiver.collect::<Vec<_>>().await, [1, 2]);
+/// # });
+/// ```
+#[cfg(feature = "sink")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sink")))]
+impl<Item, S: ?Sized + futures_sink::Sink<Item>> futures_sink::Sink<Item> for AlwaysSend<S> {
+    type Error = S::Error;
+
+    fn poll_ready(
+        self: Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<Result<(), Self::Error>> {
+        self.inner_pin_mut().poll_ready(cx)
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: Item) -> Result<(), Self::Error> {
+        self.inner_pin_mut().start_send(item)
+    }
+
+    fn poll_flush(
+        self: Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<Result<(), Self::Error>> {
+        self.inner_pin_mut().poll_flush(cx)
+    }
+
+    fn poll_close(
+        self: Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<Result<(), Self::Error>> {
+        self.inner_pin_mut().poll_close(cx)
+    }
+}
+
+/// Convenience extension trait for easy construction
+/// of the [`AlwaysSend`] wrapper for futures
+/// in method chains.
+///
+/// As an extension trait, you can import this without a name, like
+/// ```
+/// use always_send::FutureExt as _;
+/// ```
+/// for minimizing the potential for ambiguities.
+pub trait FutureExt: Future + Send + Sized {
+    fn always_send(self) -> AlwaysSend<Self> {
+        AlwaysSend::new(self)
+    }
+}
+
+impl<F: Future + Send> FutureExt for F {}
+
+/// Convenience extension trait for easy construction
+/// of the [`AlwaysSend`] wrapper for (synchronous) iterators
+/// in method chains.
+///
+/// As an extension trait, you can import this without a name, like
+/// ```
+/// use always_send::IteratorExt as _;
+/// ```
+/// for minimizing the potential for ambiguities.
+///
+/// # Examples
+///
+/// ```
+/// use always_send::IteratorExt as _;
+///
+/// let items = (0..10).map(|x| x * 2).always_send();
+/// assert_eq!(items.len(), 10);
+/// assert_eq!(items.collect::<Vec<_>>(), [0, 2, 4, 6, 8, 10, 12, 14, 16, 18]);
+///
+/// let reversed = (0..5).always_send().rev().collect::<Vec<_>>();
+/// assert_eq!(reversed, [4, 3, 2, 1, 0]);
+/// ```
+pub trait IteratorExt: Iterator + Send + Sized {
+    fn always_send(self) -> AlwaysSend<Self> {
+        AlwaysSend::new(self)
+    }
+}
+
+impl<I: Iterator + Send> IteratorExt for I {}
+
+/// Extension trait for wrapping futures in [`AlwaysSend`] and spawning them
+/// on the tokio runtime in one go.
+///
+/// As an extension trait, you can import this without a name, like
+/// ```
+/// use always_send::SpawnExt as _;
+/// ```
+/// for minimizing the potential for ambiguities.
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+pub trait SpawnExt: Future + Send + Sized {
+    /// Spawns this future, wrapped in [`AlwaysSend`], with [`tokio::spawn`].
+    ///
+    /// This is the same as `tokio::spawn(self.always_send())`, with the same requirements
+    /// and guarantees. The returned `JoinHandle` resolves to the output of this future,
+    /// without any wrapper.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside of a tokio runtime, like `tokio::spawn` does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// use always_send::SpawnExt as _;
+    ///
+    /// let handle: tokio::task::JoinHandle<i32> = async { 40 + 2 }.spawn_always_send();
+    /// assert_eq!(handle.await.unwrap(), 42);
+    /// # }
+    /// ```
+    fn spawn_always_send(self) -> tokio::task::JoinHandle<Self::Output>
+    where
+        Self: 'static,
+        Self::Output: Send + 'static,
+    {
+        tokio::spawn(AlwaysSend::new(self))
+    }
+}
+
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+impl<F: Future + Send> SpawnExt for F {}
+
+#[cfg(feature = "stream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+/// Convenience extension trait for easy construction
+/// of the [`AlwaysSend`] wrapper for streams
+/// in method chains.
+///
+/// As an extension trait, you can import this without a name, like
+/// ```
+/// use always_send::StreamExt as _;
+/// ```
+/// for minimizing the potential for ambiguities.
+pub trait StreamExt: Stream + Send + Sized {
+    fn always_send(self) -> AlwaysSend<Self> {
+        AlwaysSend::new(self)
+    }
+
+    /// Wraps this stream in [`AlwaysSend`] and boxes it up into a type-erased
+    /// `Send` stream, like [`boxed`](futures_util::StreamExt::boxed).
+    ///
+    /// Unlike the box produced by `boxed`, the compiler need not be able to
+    /// prove `Self: Send` at the call site of this method.
+    ///
+    /// There's deliberately no counterpart to
+    /// [`boxed_local`](futures_util::StreamExt::boxed_local) in this crate,
+    /// since its whole point is producing `Send` streams; if you don't need
+    /// `Send`, you don't need this crate either.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use always_send::StreamExt as _;
+    /// use futures::stream::{self, StreamExt as _};
+    ///
+    /// fn assert_send<T: Send>(_: &T) {}
+    ///
+    /// let boxed: futures::stream::BoxStream<'_, i32> = stream::iter([1, 2, 3]).boxed_send();
+    /// assert_send(&boxed);
+    /// assert_eq!(boxed.collect::<Vec<_>>().await, [1, 2, 3]);
+    /// # });
+    /// ```
+    ///
+    /// whereas there's no `boxed_local`
+    ///
+    /// ```compile_fail,E0599
+    /// use always_send::StreamExt as _;
+    ///
+    /// let _ = futures::stream::iter([1, 2, 3]).boxed_local();
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn boxed_send<'a>(self) -> Pin<alloc::boxed::Box<dyn Stream<Item = Self::Item> + Send + 'a>>
+    where
+        Self: 'a,
+    {
+        alloc::boxed::Box::pin(AlwaysSend::new(self))
+    }
+
+    /// Batches up the items of this stream into `Vec`s, emitting a batch once
+    /// either `max_size` items have accumulated, or `duration` has elapsed
+    /// since the first item of the batch arrived.
+    ///
+    /// A final, possibly smaller, batch is emitted when this stream ends.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// use always_send::StreamExt as _;
+    /// use futures::stream::{self, StreamExt as _};
+    /// use std::time::Duration;
+    ///
+    /// // flushed by size
+    /// let chunks = stream::iter(1..=5)
+    ///     .always_send()
+    ///     .chunks_timeout_always_send(2, Duration::from_secs(1));
+    /// assert_eq!(chunks.collect::<Vec<_>>().await, [vec![1, 2], vec![3, 4], vec![5]]);
+    ///
+    /// // flushed by time
+    /// let slow = stream::iter(1..=3).then(|x| async move {
+    ///     if x == 3 {
+    ///         tokio::time::sleep(Duration::from_secs(5)).await;
+    ///     }
+    ///     x
+    /// });
+    /// let chunks = slow
+    ///     .always_send()
+    ///     .chunks_timeout_always_send(10, Duration::from_secs(1));
+    /// assert_eq!(chunks.collect::<Vec<_>>().await, [vec![1, 2], vec![3]]);
+    ///
+    /// // `max_size` is only an upper bound, nothing is allocated for it upfront
+    /// let chunks = stream::iter(1..=3)
+    ///     .always_send()
+    ///     .chunks_timeout_always_send(usize::MAX, Duration::from_secs(1));
+    /// assert_eq!(chunks.collect::<Vec<_>>().await, [vec![1, 2, 3]]);
+    /// # }
+    /// ```
+    #[cfg(feature = "tokio")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+    fn chunks_timeout_always_send(
+        self,
+        max_size: usize,
+        duration: core::time::Duration,
+    ) -> AlwaysSend<stream::ChunksTimeout<Self>>
+    where
+        Self::Item: Send,
+    {
+        AlwaysSend::new(stream::ChunksTimeout::new(self, max_size, duration))
+    }
+
+    /// Collects the items of this stream into a `Vec`, until either the stream ends,
+    /// or `duration` has elapsed, whichever comes first.
+    ///
+    /// The returned `bool` is `true` if the deadline cut the stream short.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// use always_send::StreamExt as _;
+    /// use futures::stream::{self, StreamExt as _};
+    /// use std::time::Duration;
+    ///
+    /// let each_second = |n| {
+    ///     stream::iter(1..=n).then(|x| async move {
+    ///         tokio::time::sleep(Duration::from_secs(1)).await;
+    ///         x
+    ///     })
+    /// };
+    ///
+    /// // completes naturally
+    /// let (items, timed_out) = each_second(3)
+    ///     .collect_with_deadline_always_send(Duration::from_millis(3500))
+    ///     .await;
+    /// assert_eq!((items, timed_out), (vec![1, 2, 3], false));
+    ///
+    /// // truncated by the deadline
+    /// let (items, timed_out) = each_second(10)
+    ///     .collect_with_deadline_always_send(Duration::from_millis(3500))
+    ///     .await;
+    /// assert_eq!((items, timed_out), (vec![1, 2, 3], true));
+    /// # }
+    /// ```
+    #[cfg(feature = "tokio")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+    fn collect_with_deadline_always_send(
+        self,
+        duration: core::time::Duration,
+    ) -> AlwaysSend<impl Future<Output = (alloc::vec::Vec<Self::Item>, bool)>>
+    where
+        Self::Item: Send,
+    {
+        AlwaysSend::new(async move {
+            let mut stream = core::pin::pin!(self);
+            let mut deadline = core::pin::pin!(tokio::time::sleep(duration));
+            let mut items = alloc::vec::Vec::new();
+            let timed_out = core::future::poll_fn(|cx| loop {
+                if deadline.as_mut().poll(cx).is_ready() {
+                    return core::task::Poll::Ready(true);
+                }
+                match core::task::ready!(stream.as_mut().poll_next(cx)) {
+                    Some(item) => items.push(item),
+                    None => return core::task::Poll::Ready(false),
+                }
+            })
+            .await;
+            (items, timed_out)
+        })
+    }
+
+    /// Folds all items of this stream with the synchronous step `f`,
+    /// resolving to both the final accumulator and the number of items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use always_send::StreamExt as _;
+    /// use futures::stream;
+    ///
+    /// let (sum, count) = stream::iter([3, 4, 5])
+    ///     .always_send()
+    ///     .fold_with_state_always_send(0, |sum, x| sum + x)
+    ///     .await;
+    /// assert_eq!((sum, count), (12, 3));
+    /// # });
+    /// ```
+    fn fold_with_state_always_send<Acc, F>(
+        self,
+        init: Acc,
+        f: F,
+    ) -> AlwaysSend<stream::FoldWithState<Self, Acc, F>>
+    where
+        Acc: Send,
+        F: FnMut(Acc, Self::Item) -> Acc + Send,
+    {
+        AlwaysSend::new(stream::FoldWithState::new(self, init, f))
+    }
+
+    /// Runs up to `n` of the futures yielded by this stream concurrently,
+    /// like [`buffer_unordered`](futures_util::StreamExt::buffer_unordered),
+    /// but tags each output with the index of its future in this stream.
+    ///
+    /// Outputs still arrive in completion order; the index allows to
+    /// restore the original order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// use always_send::StreamExt as _;
+    /// use futures::stream::{self, StreamExt as _};
+    /// use std::time::Duration;
+    ///
+    /// let outputs = stream::iter([('a', 30), ('b', 10), ('c', 20)])
+    ///     .map(|(c, ms)| async move {
+    ///         tokio::time::sleep(Duration::from_millis(ms)).await;
+    ///         c
+    ///     })
+    ///     .always_send()
+    ///     .buffer_unordered_indexed_always_send(3)
+    ///     .collect::<Vec<_>>()
+    ///     .await;
+    /// assert_eq!(outputs, [(1, 'b'), (2, 'c'), (0, 'a')]);
+    /// # }
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn buffer_unordered_indexed_always_send(
+        self,
+        n: usize,
+    ) -> AlwaysSend<stream::BufferUnorderedIndexed<Self>>
+    where
+        Self::Item: Future + Send,
+    {
+        AlwaysSend::new(stream::BufferUnorderedIndexed::new(self, n))
+    }
+
+    /// Runs up to `n` of the futures yielded by this stream concurrently,
+    /// collecting their outputs into a `Vec` in the original order of the futures.
+    ///
+    /// This builds on [`buffer_unordered_indexed_always_send`](StreamExt::buffer_unordered_indexed_always_send),
+    /// so a slow future doesn't hold up starting the subsequent ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// use always_send::StreamExt as _;
+    /// use futures::stream::{self, StreamExt as _};
+    /// use std::time::Duration;
+    ///
+    /// let outputs = stream::iter([('a', 30), ('b', 10), ('c', 20), ('d', 5)])
+    ///     .map(|(c, ms)| async move {
+    ///         tokio::time::sleep(Duration::from_millis(ms)).await;
+    ///         c
+    ///     })
+    ///     .always_send()
+    ///     .collect_ordered_always_send(2)
+    ///     .await;
+    /// assert_eq!(outputs, ['a', 'b', 'c', 'd']);
+    /// # }
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn collect_ordered_always_send(self, n: usize) -> AlwaysSend<stream::CollectOrdered<Self>>
+    where
+        Self::Item: Future + Send,
+        <Self::Item as Future>::Output: Send,
+    {
+        AlwaysSend::new(stream::CollectOrdered::new(self, n))
+    }
+
+    /// Runs up to `limit` of the futures yielded by this stream concurrently,
+    /// collecting the first `n` outputs into a `Vec`, in completion order.
+    ///
+    /// Once `n` outputs are collected, the futures still in flight are dropped,
+    /// and no further futures are taken from this stream. If the stream ends earlier,
+    /// fewer than `n` outputs are returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// use always_send::StreamExt as _;
+    /// use futures::stream::{self, StreamExt as _};
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::time::Duration;
+    ///
+    /// let finished = AtomicUsize::new(0);
+    /// let outputs = stream::iter([50, 10, 40, 20, 30])
+    ///     .map(|ms| {
+    ///         let finished = &finished;
+    ///         async move {
+    ///             tokio::time::sleep(Duration::from_millis(ms)).await;
+    ///             finished.fetch_add(1, Ordering::SeqCst);
+    ///             ms
+    ///         }
+    ///     })
+    ///     .always_send()
+    ///     .take_concurrent_always_send(2, 3)
+    ///     .await;
+    /// assert_eq!(outputs, [10, 20]);
+    ///
+    /// // the slower futures were cancelled
+    /// tokio::time::sleep(Duration::from_millis(100)).await;
+    /// assert_eq!(finished.load(Ordering::SeqCst), 2);
+    /// # }
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn take_concurrent_always_send(
+        self,
+        n: usize,
+        limit: usize,
+    ) -> AlwaysSend<impl Future<Output = alloc::vec::Vec<<Self::Item as Future>::Output>>>
+    where
+        Self::Item: Future + Send,
+        <Self::Item as Future>::Output: Send,
+    {
+        AlwaysSend::new(async move {
+            let outputs = futures_util::StreamExt::buffer_unordered(self, limit);
+            futures_util::StreamExt::collect(futures_util::StreamExt::take(outputs, n)).await
+        })
+    }
+
+    /// Runs up to `n` of the futures yielded by this stream concurrently,
+    /// like [`buffer_unordered`](futures_util::StreamExt::buffer_unordered),
+    /// calling `on_saturated` whenever all `n` slots become busy.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero. (Unlike `buffer_unordered`, zero doesn't mean
+    /// unlimited concurrency here, since there could never be saturation.)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// use always_send::StreamExt as _;
+    /// use futures::stream::{self, StreamExt as _};
+    /// use std::time::Duration;
+    ///
+    /// let mut saturated = 0;
+    /// let mut outputs = stream::iter([30, 10, 20])
+    ///     .map(|ms| async move {
+    ///         tokio::time::sleep(Duration::from_millis(ms)).await;
+    ///         ms
+    ///     })
+    ///     .always_send()
+    ///     .buffer_unordered_instrumented_always_send(2, || saturated += 1)
+    ///     .collect::<Vec<_>>()
+    ///     .await;
+    /// outputs.sort();
+    /// assert_eq!(outputs, [10, 20, 30]);
+    /// // once for the first two futures, once more when the third replaced the fastest one
+    /// assert_eq!(saturated, 2);
+    ///
+    /// // with fewer futures than slots, all `n` slots never become busy
+    /// let mut saturated = 0;
+    /// let outputs = stream::iter([10, 20])
+    ///     .map(|ms| async move {
+    ///         tokio::time::sleep(Duration::from_millis(ms)).await;
+    ///         ms
+    ///     })
+    ///     .always_send()
+    ///     .buffer_unordered_instrumented_always_send(3, || saturated += 1)
+    ///     .collect::<Vec<_>>()
+    ///     .await;
+    /// assert_eq!(outputs, [10, 20]);
+    /// assert_eq!(saturated, 0);
+    /// # }
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn buffer_unordered_instrumented_always_send<F>(
+        self,
+        n: usize,
+        on_saturated: F,
+    ) -> AlwaysSend<stream::BufferUnorderedInstrumented<Self, F>>
+    where
+        Self::Item: Future + Send,
+        F: FnMut() + Send,
+    {
+        AlwaysSend::new(stream::BufferUnorderedInstrumented::new(
+            self,
+            n,
+            on_saturated,
+        ))
+    }
+
+    /// Flattens this stream of streams, polling up to `limit` of the inner
+    /// streams concurrently, like
+    /// [`flatten_unordered`](futures_util::StreamExt::flatten_unordered).
+    ///
+    /// A `limit` of `None` polls all inner streams concurrently.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use always_send::StreamExt as _;
+    /// use futures::stream::{self, StreamExt as _};
+    ///
+    /// let mut items = stream::iter([
+    ///     stream::iter(vec![1, 2]),
+    ///     stream::iter(vec![3]),
+    ///     stream::iter(vec![4, 5, 6]),
+    /// ])
+    /// .always_send()
+    /// .flatten_unordered_always_send(None)
+    /// .collect::<Vec<_>>()
+    /// .await;
+    /// items.sort();
+    /// assert_eq!(items, [1, 2, 3, 4, 5, 6]);
+    /// # });
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn flatten_unordered_always_send(
+        self,
+        limit: impl Into<Option<usize>>,
+    ) -> AlwaysSend<futures_util::stream::FlattenUnordered<Self>>
+    where
+        Self::Item: Stream + Unpin + Send,
+    {
+        AlwaysSend::new(futures_util::StreamExt::flatten_unordered(self, limit))
+    }
+
+    /// Yields items of this stream until the `signal` future resolves, like
+    /// [`take_until`](futures_util::StreamExt::take_until).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// use always_send::StreamExt as _;
+    /// use futures::stream::{self, StreamExt as _};
+    /// use std::time::Duration;
+    ///
+    /// let ticks = stream::iter(0..).then(|x| async move {
+    ///     tokio::time::sleep(Duration::from_millis(10)).await;
+    ///     x
+    /// });
+    /// let signal = tokio::time::sleep(Duration::from_millis(25));
+    /// let items = ticks
+    ///     .always_send()
+    ///     .take_until_always_send(signal)
+    ///     .collect::<Vec<_>>()
+    ///     .await;
+    /// assert_eq!(items, [0, 1]);
+    /// # }
+    /// ```
+    fn take_until_always_send<Fut>(
+        self,
+        signal: Fut,
+    ) -> AlwaysSend<futures_util::stream::TakeUntil<Self, Fut>>
+    where
+        Fut: Future + Send,
+        Fut::Output: Send,
+    {
+        AlwaysSend::new(futures_util::StreamExt::take_until(self, signal))
+    }
+
+    /// Maps each item of this stream through the asynchronous function `f`, like
+    /// [`then`](futures_util::StreamExt::then).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use always_send::StreamExt as _;
+    /// use futures::stream::{self, StreamExt as _};
+    ///
+    /// async fn double(x: u32) -> u32 {
+    ///     x * 2
+    /// }
+    ///
+    /// let items = stream::iter(1..=3)
+    ///     .always_send()
+    ///     .then_always_send(double)
+    ///     .collect::<Vec<_>>()
+    ///     .await;
+    /// assert_eq!(items, [2, 4, 6]);
+    /// # });
+    /// ```
+    fn then_always_send<Fut, F>(self, f: F) -> AlwaysSend<futures_util::stream::Then<Self, Fut, F>>
+    where
+        F: FnMut(Self::Item) -> Fut + Send,
+        Fut: Future + Send,
+    {
+        AlwaysSend::new(futures_util::StreamExt::then(self, f))
+    }
+
+    /// Collects a stream of pairs into two separately wrapped collections.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use always_send::StreamExt as _;
+    /// use futures::stream;
+    ///
+    /// let (numbers, letters) = stream::iter([(1_u32, 'a'), (2, 'b'), (3, 'c')])
+    ///     .always_send()
+    ///     .unzip_always_send::<_, _, Vec<u32>, String>()
+    ///     .await;
+    /// assert_eq!(numbers.inner, [1, 2, 3]);
+    /// assert_eq!(letters.inner, "abc");
+    /// # });
+    /// ```
+    fn unzip_always_send<A, B, CA, CB>(self) -> AlwaysSend<stream::Unzip<Self, CA, CB>>
+    where
+        Self: Stream<Item = (A, B)>,
+        CA: Default + Extend<A> + Send,
+        CB: Default + Extend<B> + Send,
+    {
+        AlwaysSend::new(stream::Unzip::new(self))
+    }
+
+    /// Filters and maps the items of this stream with the asynchronous function `f`, like
+    /// [`filter_map`](futures_util::StreamExt::filter_map).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use always_send::StreamExt as _;
+    /// use futures::stream::{self, StreamExt as _};
+    ///
+    /// let numbers = stream::iter(["1", "two", "3", "", "5"])
+    ///     .always_send()
+    ///     .filter_map_always_send(|s| async move { s.parse::<i32>().ok() })
+    ///     .collect::<Vec<_>>()
+    ///     .await;
+    /// assert_eq!(numbers, [1, 3, 5]);
+    /// # });
+    /// ```
+    fn filter_map_always_send<Fut, F, T>(
+        self,
+        f: F,
+    ) -> AlwaysSend<futures_util::stream::FilterMap<Self, Fut, F>>
+    where
+        F: FnMut(Self::Item) -> Fut + Send,
+        Fut: Future<Output = Option<T>> + Send,
+    {
+        AlwaysSend::new(futures_util::StreamExt::filter_map(self, f))
+    }
+
+    /// Threads a state through the asynchronous step `f`, which is called for each
+    /// item of this stream and resolves to the new state along with the item to yield.
+    ///
+    /// Unlike [`scan`](futures_util::StreamExt::scan), the state is passed to `f`
+    /// by value, so it can be used across `.await` points inside the step.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use always_send::StreamExt as _;
+    /// use futures::stream::{self, StreamExt as _};
+    ///
+    /// let totals = stream::iter([1, 2, 3, 4])
+    ///     .always_send()
+    ///     .scan_async_always_send(0, |total, x| async move {
+    ///         let total = total + x;
+    ///         (total, total)
+    ///     })
+    ///     .collect::<Vec<_>>()
+    ///     .await;
+    /// assert_eq!(totals, [1, 3, 6, 10]);
+    /// # });
+    /// ```
+    fn scan_async_always_send<St, B, Fut, F>(
+        self,
+        init: St,
+        f: F,
+    ) -> AlwaysSend<stream::ScanAsync<Self, St, Fut, F>>
+    where
+        St: Send,
+        F: FnMut(St, Self::Item) -> Fut + Send,
+        Fut: Future<Output = (St, B)> + Send,
+    {
+        AlwaysSend::new(stream::ScanAsync::new(self, init, f))
+    }
+
+    /// Wraps this stream as the left variant of an [`Either`](futures_util::future::Either),
+    /// like [`left_stream`](futures_util::StreamExt::left_stream).
+    ///
+    /// Together with [`right_stream_always_send`](StreamExt::right_stream_always_send),
+    /// this allows to choose between two different stream types at runtime,
+    /// while keeping a single `Send` type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use always_send::StreamExt as _;
+    /// use futures::stream::{self, StreamExt as _};
+    ///
+    /// let choose = |evens: bool| {
+    ///     if evens {
+    ///         stream::iter([0, 2, 4]).always_send().left_stream_always_send()
+    ///     } else {
+    ///         stream::repeat(1).take(3).always_send().right_stream_always_send()
+    ///     }
+    /// };
+    /// assert_eq!(choose(true).collect::<Vec<_>>().await, [0, 2, 4]);
+    /// assert_eq!(choose(false).collect::<Vec<_>>().await, [1, 1, 1]);
+    /// # });
+    /// ```
+    fn left_stream_always_send<B>(self) -> AlwaysSend<futures_util::future::Either<Self, B>>
+    where
+        B: Stream<Item = Self::Item> + Send,
+    {
+        AlwaysSend::new(futures_util::StreamExt::left_stream(self))
+    }
+
+    /// Wraps this stream as the right variant of an [`Either`](futures_util::future::Either),
+    /// like [`right_stream`](futures_util::StreamExt::right_stream).
+    ///
+    /// See [`left_stream_always_send`](StreamExt::left_stream_always_send) for an example.
+    fn right_stream_always_send<A>(self) -> AlwaysSend<futures_util::future::Either<A, Self>>
+    where
+        A: Stream<Item = Self::Item> + Send,
+    {
+        AlwaysSend::new(futures_util::StreamExt::right_stream(self))
+    }
+
+    /// Skips the first `n` items of this stream, like
+    /// [`skip`](futures_util::StreamExt::skip).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use always_send::StreamExt as _;
+    /// use futures::stream::{self, StreamExt as _};
+    ///
+    /// let items = stream::iter(0..10)
+    ///     .always_send()
+    ///     .skip_always_send(7)
+    ///     .collect::<Vec<_>>()
+    ///     .await;
+    /// assert_eq!(items, [7, 8, 9]);
+    /// # });
+    /// ```
+    fn skip_always_send(self, n: usize) -> AlwaysSend<futures_util::stream::Skip<Self>> {
+        AlwaysSend::new(futures_util::StreamExt::skip(self, n))
+    }
+
+    /// Yields the first item of this stream, and then every `step`-th item after it,
+    /// like [`Iterator::step_by`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use always_send::StreamExt as _;
+    /// use futures::stream::{self, StreamExt as _};
+    ///
+    /// let items = stream::iter(0..10)
+    ///     .always_send()
+    ///     .step_by_always_send(2)
+    ///     .collect::<Vec<_>>()
+    ///     .await;
+    /// assert_eq!(items, [0, 2, 4, 6, 8]);
+    ///
+    /// // composes with other adapters
+    /// let items = stream::iter(0..10)
+    ///     .always_send()
+    ///     .skip_always_send(1)
+    ///     .step_by_always_send(3)
+    ///     .collect::<Vec<_>>()
+    ///     .await;
+    /// assert_eq!(items, [1, 4, 7]);
+    /// # });
+    /// ```
+    fn step_by_always_send(self, step: usize) -> AlwaysSend<stream::StepBy<Self>> {
+        AlwaysSend::new(stream::StepBy::new(self, step))
+    }
+
+    /// Yields overlapping windows of `size` consecutive items of this stream,
+    /// like [`slice::windows`].
+    ///
+    /// The first window is yielded once `size` items have arrived, and then one more
+    /// window for each further item. A stream with fewer than `size` items yields
+    /// no windows at all.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use always_send::StreamExt as _;
+    /// use futures::stream::{self, Stream as _, StreamExt as _};
+    ///
+    /// let windows = stream::iter([1, 2, 3, 4]).windows_always_send(2);
+    /// assert_eq!(windows.collect::<Vec<_>>().await, [[1, 2], [2, 3], [3, 4]]);
+    ///
+    /// let windows = stream::iter([1, 2]).windows_always_send(3);
+    /// assert_eq!(windows.collect::<Vec<_>>().await, Vec::<Vec<_>>::new());
+    ///
+    /// // nothing is allocated for `size` upfront
+    /// let windows = stream::iter([1, 2]).windows_always_send(usize::MAX);
+    /// assert_eq!(windows.collect::<Vec<_>>().await, Vec::<Vec<_>>::new());
+    ///
+    /// // the size hint stays exact, even for huge streams
+    /// let windows = stream::iter(0..usize::MAX).windows_always_send(usize::MAX);
+    /// assert_eq!(windows.size_hint(), (1, Some(1)));
+    /// # });
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn windows_always_send(self, size: usize) -> AlwaysSend<stream::Windows<Self>>
+    where
+        Self::Item: Clone + Send,
+    {
+        AlwaysSend::new(stream::Windows::new(self, size))
+    }
+
+    /// Drops consecutive items of this stream with the same key, as computed by `key_fn`,
+    /// like [`Vec::dedup_by_key`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.dedup_by_key).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use always_send::StreamExt as _;
+    /// use futures::stream::{self, StreamExt as _};
+    ///
+    /// struct Record {
+    ///     id: u32,
+    ///     version: u32,
+    /// }
+    ///
+    /// let records = stream::iter([(1, 0), (1, 1), (2, 0), (1, 2), (1, 3)])
+    ///     .map(|(id, version)| Record { id, version })
+    ///     .dedup_by_key_always_send(|record| record.id)
+    ///     .map(|record| (record.id, record.version))
+    ///     .collect::<Vec<_>>()
+    ///     .await;
+    /// assert_eq!(records, [(1, 0), (2, 0), (1, 2)]);
+    /// # });
+    /// ```
+    fn dedup_by_key_always_send<K, F>(self, key_fn: F) -> AlwaysSend<stream::DedupByKey<Self, F, K>>
+    where
+        K: PartialEq + Send,
+        F: FnMut(&Self::Item) -> K + Send,
+    {
+        AlwaysSend::new(stream::DedupByKey::new(self, key_fn))
+    }
+
+    /// Yields to the executor once before each item of this stream, by returning
+    /// `Poll::Pending` (after waking the task again right away).
+    ///
+    /// This allows cooperative scheduling without any timer, e.g. under `no_std`.
+    /// The end of the stream is preceded by such a yield, too.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use always_send::StreamExt as _;
+    /// use futures::stream::{self, Stream};
+    /// use std::pin::pin;
+    /// use std::task::{Context, Poll};
+    ///
+    /// let mut items = pin!(stream::iter([1, 2]).yield_between_always_send());
+    /// let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+    /// assert_eq!(items.as_mut().poll_next(&mut cx), Poll::Pending);
+    /// assert_eq!(items.as_mut().poll_next(&mut cx), Poll::Ready(Some(1)));
+    /// assert_eq!(items.as_mut().poll_next(&mut cx), Poll::Pending);
+    /// assert_eq!(items.as_mut().poll_next(&mut cx), Poll::Ready(Some(2)));
+    /// assert_eq!(items.as_mut().poll_next(&mut cx), Poll::Pending);
+    /// assert_eq!(items.as_mut().poll_next(&mut cx), Poll::Ready(None));
+    /// ```
+    fn yield_between_always_send(self) -> AlwaysSend<stream::YieldBetween<Self>> {
+        AlwaysSend::new(stream::YieldBetween::new(self))
+    }
+
+    /// Calls `f` with the running index and a reference to each item of this stream,
+    /// before yielding the item, like an indexed
+    /// [`inspect`](futures_util::StreamExt::inspect).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use always_send::StreamExt as _;
+    /// use futures::stream::{self, StreamExt as _};
+    ///
+    /// let mut seen = Vec::new();
+    /// let items = stream::iter(['a', 'b', 'c'])
+    ///     .always_send()
+    ///     .inspect_indexed_always_send(|i, &c| seen.push((i, c)))
+    ///     .collect::<Vec<_>>()
+    ///     .await;
+    /// assert_eq!(items, ['a', 'b', 'c']);
+    /// assert_eq!(seen, [(0, 'a'), (1, 'b'), (2, 'c')]);
+    /// # });
+    /// ```
+    fn inspect_indexed_always_send<F>(self, f: F) -> AlwaysSend<stream::InspectIndexed<Self, F>>
+    where
+        F: FnMut(usize, &Self::Item) + Send,
+    {
+        AlwaysSend::new(stream::InspectIndexed::new(self, f))
+    }
+
+    /// Concatenates all items of this stream into a single extendable value, like
+    /// [`concat`](futures_util::StreamExt::concat).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use always_send::StreamExt as _;
+    /// use futures::stream;
+    ///
+    /// let bytes = stream::iter([vec![1_u8, 2], vec![], vec![3]])
+    ///     .always_send()
+    ///     .concat_always_send()
+    ///     .await;
+    /// assert_eq!(bytes, [1, 2, 3]);
+    /// # });
+    /// ```
+    fn concat_always_send(self) -> AlwaysSend<futures_util::stream::Concat<Self>>
+    where
+        Self::Item: Extend<<Self::Item as IntoIterator>::Item> + IntoIterator + Default + Send,
+    {
+        AlwaysSend::new(futures_util::StreamExt::concat(self))
+    }
+
+    /// Forwards all `Ok` items of this stream into `sink`, like
+    /// [`forward`](futures_util::StreamExt::forward), while converting
+    /// the errors of this stream into the sink's error type with `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use always_send::StreamExt as _;
+    /// use futures::channel::mpsc;
+    /// use futures::sink::SinkExt as _;
+    /// use futures::stream::{self, StreamExt as _};
+    ///
+    /// let (tx, rx) = mpsc::unbounded();
+    /// // the sink's error type is `String`
+    /// let sink = tx.sink_map_err(|e| e.to_string());
+    /// let result = stream::iter(["1", "2", "x", "4"])
+    ///     .map(|s| s.parse::<i32>())
+    ///     .always_send()
+    ///     .forward_map_err_always_send(sink, |e| e.to_string())
+    ///     .await;
+    /// assert_eq!(result, Err("invalid digit found in string".to_owned()));
+    /// assert_eq!(rx.collect::<Vec<_>>().await, [1, 2]);
+    /// # });
+    /// ```
+    #[cfg(feature = "sink")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sink")))]
+    fn forward_map_err_always_send<T, E, Si, F>(
+        self,
+        sink: Si,
+        f: F,
+    ) -> AlwaysSend<futures_util::stream::Forward<futures_util::stream::MapErr<Self, F>, Si>>
+    where
+        Self: Stream<Item = Result<T, E>>,
+        T: Send,
+        Si: futures_sink::Sink<T> + Send,
+        F: FnMut(E) -> Si::Error + Send,
+    {
+        let stream = futures_util::TryStreamExt::map_err(self, f);
+        AlwaysSend::new(futures_util::StreamExt::forward(stream, sink))
+    }
+
+    /// Feeds all items of this stream into `sink`, then flushes it, and returns
+    /// the sink for further use.
+    ///
+    /// Unlike with [`forward`](futures_util::StreamExt::forward), the sink isn't closed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use always_send::StreamExt as _;
+    /// use futures::sink::SinkExt as _;
+    /// use futures::stream;
+    ///
+    /// let sink = stream::iter([1, 2, 3])
+    ///     .collect_to_sink_always_send(Vec::new())
+    ///     .await
+    ///     .unwrap();
+    /// assert_eq!(sink, [1, 2, 3]);
+    ///
+    /// // the sink is still usable
+    /// let mut sink = stream::iter([4]).collect_to_sink_always_send(sink).await.unwrap();
+    /// sink.send(5).await.unwrap();
+    /// assert_eq!(sink, [1, 2, 3, 4, 5]);
+    /// # });
+    /// ```
+    #[cfg(feature = "sink")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sink")))]
+    fn collect_to_sink_always_send<Si>(
+        self,
+        sink: Si,
+    ) -> AlwaysSend<impl Future<Output = Result<Si, Si::Error>>>
+    where
+        Si: futures_sink::Sink<Self::Item> + Unpin + Send,
+        Self::Item: Send,
+    {
+        AlwaysSend::new(async move {
+            let mut stream = core::pin::pin!(self);
+            let mut sink = sink;
+            while let Some(item) = futures_util::StreamExt::next(&mut stream).await {
+                futures_util::SinkExt::feed(&mut sink, item).await?;
+            }
+            futures_util::SinkExt::flush(&mut sink).await?;
+            Ok(sink)
+        })
+    }
+
+    /// Resolves to the first item of this stream matching `predicate`,
+    /// or `None` if there is none, like [`Iterator::find`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use always_send::StreamExt as _;
+    /// use futures::stream;
+    ///
+    /// let even = stream::iter([1, 3, 4, 5, 6]).find_always_send(|x| x % 2 == 0).await;
+    /// assert_eq!(even, Some(4));
+    ///
+    /// let even = stream::iter([1, 3, 5]).find_always_send(|x| x % 2 == 0).await;
+    /// assert_eq!(even, None);
+    /// # });
+    /// ```
+    fn find_always_send<F>(
+        self,
+        mut predicate: F,
+    ) -> AlwaysSend<impl Future<Output = Option<Self::Item>>>
+    where
+        F: FnMut(&Self::Item) -> bool + Send,
+    {
+        AlwaysSend::new(async move {
+            let mut stream = core::pin::pin!(self);
+            while let Some(item) = futures_util::StreamExt::next(&mut stream).await {
+                if predicate(&item) {
+                    return Some(item);
+                }
+            }
+            None
+        })
+    }
+
+    /// Resolves to the first item of this stream, or `None` if it's empty.
+    ///
+    /// Unlike [`into_future_always_send`](StreamExt::into_future_always_send),
+    /// this drops the rest of the stream, and doesn't require `Self: Unpin`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use always_send::StreamExt as _;
+    /// use futures::stream;
+    ///
+    /// assert_eq!(stream::iter([1, 2, 3]).first_always_send().await, Some(1));
+    /// assert_eq!(stream::empty::<i32>().first_always_send().await, None);
+    /// # });
+    /// ```
+    fn first_always_send(self) -> AlwaysSend<impl Future<Output = Option<Self::Item>>> {
+        AlwaysSend::new(async move {
+            let mut stream = core::pin::pin!(self);
+            futures_util::StreamExt::next(&mut stream).await
+        })
+    }
+
+    /// Counts the items of this stream, like
+    /// [`count`](futures_util::StreamExt::count).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use always_send::StreamExt as _;
+    /// use futures::stream;
+    ///
+    /// let n = stream::iter(0..100).always_send().count_always_send().await;
+    /// assert_eq!(n, 100);
+    /// # });
+    /// ```
+    fn count_always_send(self) -> AlwaysSend<futures_util::stream::Count<Self>> {
+        AlwaysSend::new(futures_util::StreamExt::count(self))
+    }
+
+    /// Sums up the items of this stream, like [`Iterator::sum`].
+    ///
+    /// Besides `T: Sum<Self::Item>`, this needs `T: Sum<T>` for adding up
+    /// the items one at a time, as they arrive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use always_send::StreamExt as _;
+    /// use futures::stream;
+    ///
+    /// let sum = stream::iter(1..=4).always_send().sum_always_send::<i32>().await;
+    /// assert_eq!(sum, 10);
+    /// # });
+    /// ```
+    fn sum_always_send<T>(self) -> AlwaysSend<stream::Sum<Self, T>>
+    where
+        T: core::iter::Sum<Self::Item> + core::iter::Sum<T> + Send,
+    {
+        AlwaysSend::new(stream::Sum::new(self))
+    }
+
+    /// Multiplies the items of this stream, like [`Iterator::product`].
+    ///
+    /// Besides `T: Product<Self::Item>`, this needs `T: Product<T>` for multiplying
+    /// the items one at a time, as they arrive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use always_send::StreamExt as _;
+    /// use futures::stream;
+    ///
+    /// let product = stream::iter(1..=4).always_send().product_always_send::<i32>().await;
+    /// assert_eq!(product, 24);
+    /// # });
+    /// ```
+    fn product_always_send<T>(self) -> AlwaysSend<stream::Product<Self, T>>
+    where
+        T: core::iter::Product<Self::Item> + core::iter::Product<T> + Send,
+    {
+        AlwaysSend::new(stream::Product::new(self))
+    }
+
+    /// Converts this stream into a future resolving to its next item
+    /// together with the (wrapped) rest of the stream, like
+    /// [`into_future`](futures_util::StreamExt::into_future).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use always_send::StreamExt as _;
+    /// use futures::stream::{self, StreamExt as _};
+    ///
+    /// let (first, rest) = stream::iter(1..=4)
+    ///     .always_send()
+    ///     .into_future_always_send()
+    ///     .await;
+    /// assert_eq!(first, Some(1));
+    /// assert_eq!(rest.collect::<Vec<_>>().await, [2, 3, 4]);
+    /// # });
+    /// ```
+    fn into_future_always_send(self) -> AlwaysSend<stream::StreamFuture<Self>>
+    where
+        Self: Unpin,
+    {
+        AlwaysSend::new(stream::StreamFuture::new(self))
+    }
+
+    /// Fuses this stream, so that it keeps returning `None` after it ended,
+    /// even if the original stream would misbehave, like
+    /// [`fuse`](futures_util::StreamExt::fuse).
+    ///
+    /// The result implements [`FusedStream`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use always_send::StreamExt as _;
+    /// use futures::stream::{self, FusedStream as _, StreamExt as _};
+    ///
+    /// let mut fused = stream::iter([1]).always_send().fuse_always_send();
+    /// assert!(!fused.is_terminated());
+    /// assert_eq!(fused.next().await, Some(1));
+    /// assert_eq!(fused.next().await, None);
+    /// assert_eq!(fused.next().await, None);
+    /// assert!(fused.is_terminated());
+    /// # });
+    /// ```
+    fn fuse_always_send(self) -> AlwaysSend<futures_util::stream::Fuse<Self>> {
+        AlwaysSend::new(futures_util::StreamExt::fuse(self))
+    }
+
+    /// Collects all items of this stream into a `Vec`, each tagged with its arrival index.
+    ///
+    /// This can help debugging the order of items in concurrent pipelines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use always_send::StreamExt as _;
+    /// use futures::stream;
+    ///
+    /// let items = stream::iter(['a', 'b', 'c'])
+    ///     .always_send()
+    ///     .collect_with_indices_always_send()
+    ///     .await;
+    /// assert_eq!(items, [(0, 'a'), (1, 'b'), (2, 'c')]);
+    /// # });
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[allow(clippy::type_complexity)]
+    fn collect_with_indices_always_send(
+        self,
+    ) -> AlwaysSend<
+        futures_util::stream::Collect<
+            futures_util::stream::Enumerate<Self>,
+            alloc::vec::Vec<(usize, Self::Item)>,
+        >,
+    >
+    where
+        Self::Item: Send,
+    {
+        let enumerated = futures_util::StreamExt::enumerate(self);
+        AlwaysSend::new(futures_util::StreamExt::collect(enumerated))
+    }
+
+    /// Limits the time to wait for each item of this stream to `duration`.
+    ///
+    /// Whenever the next item takes longer than `duration` to arrive,
+    /// an `Err(Elapsed)` is yielded, and waiting for the item continues with a fresh timer.
+    /// Uses [`futures_timer::Delay`], so it doesn't depend on any particular runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use always_send::StreamExt as _;
+    /// use futures::stream::{self, StreamExt as _};
+    /// use futures_timer::Delay;
+    /// use std::time::Duration;
+    ///
+    /// let slow = stream::iter([1, 2]).then(|x| async move {
+    ///     if x == 2 {
+    ///         Delay::new(Duration::from_millis(200)).await;
+    ///     }
+    ///     x
+    /// });
+    /// let items = slow
+    ///     .always_send()
+    ///     .timeout_each_always_send(Duration::from_millis(50))
+    ///     .collect::<Vec<_>>()
+    ///     .await;
+    /// assert_eq!(items.first(), Some(&Ok(1)));
+    /// assert!(items[1].is_err());
+    /// assert_eq!(items.last(), Some(&Ok(2)));
+    /// # });
+    /// ```
+    #[cfg(feature = "futures-timer")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "futures-timer")))]
+    fn timeout_each_always_send(
+        self,
+        duration: core::time::Duration,
+    ) -> AlwaysSend<stream::TimeoutEach<Self>> {
+        AlwaysSend::new(stream::TimeoutEach::new(self, duration))
+    }
+
+    /// Splits this combined stream and sink into separately wrapped halves, like
+    /// [`split`](futures_util::StreamExt::split).
+    ///
+    /// Both halves can be moved into different spawned tasks. Their types
+    /// are nameable, e.g. for storing them in struct fields.
+    ///
+    /// There's deliberately no `Framed`-specific variant of this method: for a
+    /// `tokio_util::codec::Framed`, this already gives halves of the nameable types
+    /// `AlwaysSend<SplitSink<Framed<..>, _>>` and `AlwaysSend<SplitStream<Framed<..>>>`,
+    /// as shown below, and a separate method would only add a dependency on `tokio-util`.
+    ///
+    /// # Examples
+    ///
+    /// With halves of a `tokio_util::codec::Framed`:
+    ///
+    /// ```
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// use always_send::{AlwaysSend, StreamExt as _};
+    /// use futures::sink::SinkExt as _;
+    /// use futures::stream::{SplitSink, SplitStream, StreamExt as _};
+    /// use tokio::io::DuplexStream;
+    /// use tokio_util::codec::{Framed, LinesCodec};
+    ///
+    /// type Lines = Framed<DuplexStream, LinesCodec>;
+    ///
+    /// struct Connection {
+    ///     sink: AlwaysSend<SplitSink<Lines, String>>,
+    ///     stream: AlwaysSend<SplitStream<Lines>>,
+    /// }
+    ///
+    /// let (a, b) = tokio::io::duplex(64);
+    /// let (sink, stream) = Framed::new(a, LinesCodec::new()).split_always_send();
+    /// let connection = Connection { sink, stream };
+    /// let mut peer = Framed::new(b, LinesCodec::new());
+    ///
+    /// let Connection { mut sink, mut stream } = connection;
+    /// let sender = tokio::spawn(async move {
+    ///     sink.send("ping".to_owned()).await.unwrap();
+    /// });
+    /// let receiver = tokio::spawn(async move {
+    ///     stream.next().await.unwrap().unwrap()
+    /// });
+    /// assert_eq!(peer.next().await.unwrap().unwrap(), "ping");
+    /// peer.send("pong").await.unwrap();
+    /// sender.await.unwrap();
+    /// assert_eq!(receiver.await.unwrap(), "pong");
+    /// # }
+    /// ```
+    #[cfg(all(feature = "sink", feature = "alloc"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "sink", feature = "alloc"))))]
+    fn split_always_send<Item>(
+        self,
+    ) -> (
+        AlwaysSend<futures_util::stream::SplitSink<Self, Item>>,
+        AlwaysSend<futures_util::stream::SplitStream<Self>>,
+    )
+    where
+        Self: futures_sink::Sink<Item>,
+        Item: Send,
+    {
+        let (sink, stream) = futures_util::StreamExt::split(self);
+        (AlwaysSend::new(sink), AlwaysSend::new(stream))
+    }
+
+    /// Duplicates this stream into two branches, which both yield all of its items, in order.
+    ///
+    /// Items pulled from this stream by one branch are cloned and buffered for the other
+    /// one, so if one branch falls behind, the buffer grows until it catches up (or is dropped).
+    /// The branches can be polled independently, e.g. by separately spawned tasks;
+    /// dropping one of them also drops the items still buffered for it.
+    ///
+    /// This requires the `std` feature, not just `alloc`, because the branches
+    /// share the original stream through a [`std::sync::Mutex`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use always_send::StreamExt as _;
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, StreamExt as _};
+    ///
+    /// let (left, right) = stream::iter(1..=1000).tee_always_send();
+    /// let left = std::thread::spawn(|| block_on(left.collect::<Vec<_>>()));
+    /// let right = std::thread::spawn(|| block_on(right.collect::<Vec<_>>()));
+    /// let expected = (1..=1000).collect::<Vec<_>>();
+    /// assert_eq!(left.join().unwrap(), expected);
+    /// assert_eq!(right.join().unwrap(), expected);
+    ///
+    /// // items buffered for a dropped branch are released right away
+    /// let item = std::sync::Arc::new(());
+    /// let (mut left, right) = stream::iter(vec![item.clone(); 3]).tee_always_send();
+    /// while block_on(left.next()).is_some() {}
+    /// assert_eq!(std::sync::Arc::strong_count(&item), 4);
+    /// drop(right);
+    /// assert_eq!(std::sync::Arc::strong_count(&item), 1);
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    fn tee_always_send(self) -> (AlwaysSend<stream::Tee<Self>>, AlwaysSend<stream::Tee<Self>>)
+    where
+        Self::Item: Clone + Send,
+    {
+        let (first, second) = stream::Tee::new(self);
+        (AlwaysSend::new(first), AlwaysSend::new(second))
+    }
+
+    /// Groups runs of consecutive items of this stream into `Vec`s,
+    /// where `same_group` decides whether two adjacent items belong together,
+    /// like [`slice::chunk_by`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use always_send::StreamExt as _;
+    /// use futures::stream::{self, StreamExt as _};
+    ///
+    /// let groups = stream::iter([1, 1, 2, 3, 3, 3])
+    ///     .always_send()
+    ///     .group_by_always_send(|a, b| a == b)
+    ///     .collect::<Vec<_>>()
+    ///     .await;
+    /// assert_eq!(groups, [vec![1, 1], vec![2], vec![3, 3, 3]]);
+    /// # });
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn group_by_always_send<F>(self, same_group: F) -> AlwaysSend<stream::GroupBy<Self, F>>
+    where
+        F: FnMut(&Self::Item, &Self::Item) -> bool + Send,
+        Self::Item: Send,
+    {
+        AlwaysSend::new(stream::GroupBy::new(self, same_group))
+    }
+}
+
+#[cfg(feature = "stream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+impl<S: Stream + Send> StreamExt for S {}
+
+#[cfg(feature = "stream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+/// Convenience extension trait for wrapping combinators on fallible futures
+/// into the [`AlwaysSend`] wrapper in method chains.
+///
+/// As an extension trait, you can import this without a name, like
+/// ```
+/// use always_send::TryFutureExt as _;
+/// ```
+/// for minimizing the potential for ambiguities.
+pub trait TryFutureExt: futures_core::TryFuture + Send + Sized {
+    /// Converts the error of this future into a boxed `dyn Error + Send + Sync`
+    /// trait object, like <code>[err_into](futures_util::TryFutureExt::err_into)::<Box<dyn Error + Send + Sync>>()</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// use always_send::TryFutureExt as _;
+    ///
+    /// let parse = async { "oops".parse::<i32>() }.map_err_boxed_always_send();
+    /// let err = tokio::spawn(parse).await.unwrap().unwrap_err();
+    /// assert_eq!(err.to_string(), "invalid digit found in string");
+    /// # }
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn map_err_boxed_always_send(
+        self,
+    ) -> AlwaysSend<
+        futures_util::future::ErrInto<
+            Self,
+            alloc::boxed::Box<dyn core::error::Error + Send + Sync>,
+        >,
+    >
+    where
+        Self::Error: core::error::Error + Send + Sync + 'static,
+    {
+        AlwaysSend::new(futures_util::TryFutureExt::err_into(self))
+    }
+}
+
+#[cfg(feature = "stream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+impl<F: futures_core::TryFuture + Send> TryFutureExt for F {}
+
+#[cfg(feature = "stream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+/// Convenience extension trait for wrapping combinators on fallible streams
+/// into the [`AlwaysSend`] wrapper in method chains.
+///
+/// As an extension trait, you can import this without a name, like
+/// ```
+/// use always_send::TryStreamExt as _;
+/// ```
+/// for minimizing the potential for ambiguities.
+pub trait TryStreamExt: futures_core::TryStream + Send + Sized {
+    /// Collects all `Ok` items of this stream into a collection `C`,
+    /// stopping early at the first error, like
+    /// [`try_collect`](futures_util::TryStreamExt::try_collect).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use always_send::TryStreamExt as _;
+    /// use futures::stream;
+    ///
+    /// let all_ok = stream::iter([Ok::<_, &str>(1), Ok(2), Ok(3)])
+    ///     .try_collect_always_send::<Vec<_>>()
+    ///     .await;
+    /// assert_eq!(all_ok, Ok(vec![1, 2, 3]));
+    ///
+    /// let early_err = stream::iter([Ok(1), Err("bad"), Ok(3)])
+    ///     .try_collect_always_send::<Vec<_>>()
+    ///     .await;
+    /// assert_eq!(early_err, Err("bad"));
+    /// # });
+    /// ```
+    fn try_collect_always_send<C>(self) -> AlwaysSend<futures_util::stream::TryCollect<Self, C>>
+    where
+        C: Default + Extend<Self::Ok> + Send,
+    {
+        AlwaysSend::new(futures_util::TryStreamExt::try_collect(self))
+    }
+
+    /// Maps the `Ok` items of this stream with `f`, like
+    /// [`map_ok`](futures_util::TryStreamExt::map_ok).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use always_send::TryStreamExt as _;
+    /// use futures::stream::{self, StreamExt as _};
+    ///
+    /// let items = stream::iter([Ok(1), Err("bad"), Ok(3)])
+    ///     .map_ok_always_send(|x| x * 10)
+    ///     .collect::<Vec<_>>()
+    ///     .await;
+    /// assert_eq!(items, [Ok(10), Err("bad"), Ok(30)]);
+    /// # });
+    /// ```
+    fn map_ok_always_send<T, F>(self, f: F) -> AlwaysSend<futures_util::stream::MapOk<Self, F>>
+    where
+        F: FnMut(Self::Ok) -> T + Send,
+    {
+        AlwaysSend::new(futures_util::TryStreamExt::map_ok(self, f))
+    }
+
+    /// Chains the fallible asynchronous computation `f` onto the `Ok` items of this stream, like
+    /// [`and_then`](futures_util::TryStreamExt::and_then).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use always_send::TryStreamExt as _;
+    /// use futures::stream::{self, TryStreamExt as _};
+    ///
+    /// let validate = |x: i32| async move {
+    ///     if x >= 0 { Ok(x) } else { Err(format!("negative: {x}")) }
+    /// };
+    ///
+    /// let valid = stream::iter([Ok(1), Ok(2), Ok(3)])
+    ///     .and_then_always_send(validate)
+    ///     .try_collect::<Vec<_>>()
+    ///     .await;
+    /// assert_eq!(valid, Ok(vec![1, 2, 3]));
+    ///
+    /// let invalid = stream::iter([Ok(1), Ok(-2), Ok(3)])
+    ///     .and_then_always_send(validate)
+    ///     .try_collect::<Vec<_>>()
+    ///     .await;
+    /// assert_eq!(invalid, Err("negative: -2".to_owned()));
+    /// # });
+    /// ```
+    fn and_then_always_send<Fut, F>(
+        self,
+        f: F,
+    ) -> AlwaysSend<futures_util::stream::AndThen<Self, Fut, F>>
+    where
+        F: FnMut(Self::Ok) -> Fut + Send,
+        Fut: futures_core::TryFuture<Error = Self::Error> + Send,
+    {
+        AlwaysSend::new(futures_util::TryStreamExt::and_then(self, f))
+    }
+
+    /// Runs up to `n` of the fallible futures yielded by this stream concurrently, like
+    /// [`try_buffer_unordered`](futures_util::TryStreamExt::try_buffer_unordered).
+    ///
+    /// After the first error, no further futures are started, and the futures still
+    /// in flight are cancelled once the stream is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// use always_send::TryStreamExt as _;
+    /// use futures::stream::{self, TryStreamExt as _};
+    /// use futures::FutureExt as _;
+    /// use std::sync::atomic::{AtomicBool, Ordering};
+    /// use std::time::Duration;
+    ///
+    /// let task = |ms: u64, result: Result<u64, u64>| async move {
+    ///     tokio::time::sleep(Duration::from_millis(ms)).await;
+    ///     result
+    /// };
+    ///
+    /// let tasks = [task(30, Ok(1)), task(10, Ok(2)), task(20, Ok(3))];
+    /// let mut all_ok = stream::iter(tasks.map(Ok))
+    ///     .try_buffer_unordered_always_send(2)
+    ///     .try_collect::<Vec<_>>()
+    ///     .await
+    ///     .unwrap();
+    /// all_ok.sort();
+    /// assert_eq!(all_ok, [1, 2, 3]);
+    ///
+    /// let slow_finished = AtomicBool::new(false);
+    /// let slow = async {
+    ///     task(100, Ok(1)).await?;
+    ///     slow_finished.store(true, Ordering::SeqCst);
+    ///     Ok(1)
+    /// };
+    /// let tasks = [slow.left_future(), task(10, Err(2)).right_future()];
+    /// let early_err = stream::iter(tasks.map(Ok))
+    ///     .try_buffer_unordered_always_send(2)
+    ///     .try_collect::<Vec<_>>()
+    ///     .await;
+    /// assert_eq!(early_err, Err(2));
+    /// tokio::time::sleep(Duration::from_millis(200)).await;
+    /// assert!(!slow_finished.load(Ordering::SeqCst));
+    /// # }
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn try_buffer_unordered_always_send(
+        self,
+        n: usize,
+    ) -> AlwaysSend<futures_util::stream::TryBufferUnordered<Self>>
+    where
+        Self::Ok: futures_core::TryFuture<Error = Self::Error> + Send,
+    {
+        AlwaysSend::new(futures_util::TryStreamExt::try_buffer_unordered(self, n))
+    }
+
+    /// Flattens this stream of streams, with errors at either level
+    /// ending the stream, like [`try_flatten`](futures_util::TryStreamExt::try_flatten).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use always_send::TryStreamExt as _;
+    /// use futures::stream::{self, TryStreamExt as _};
+    ///
+    /// let nested = stream::iter([
+    ///     Ok(stream::iter(vec![Ok(1), Ok(2)])),
+    ///     Ok(stream::iter(vec![Ok(3)])),
+    /// ]);
+    /// let items = nested.try_flatten_always_send().try_collect::<Vec<_>>().await;
+    /// assert_eq!(items, Ok::<_, &str>(vec![1, 2, 3]));
+    ///
+    /// let nested = stream::iter([
+    ///     Ok(stream::iter(vec![Ok(1), Err("inner")])),
+    ///     Ok(stream::iter(vec![Ok(3)])),
+    /// ]);
+    /// let items = nested.try_flatten_always_send().try_collect::<Vec<_>>().await;
+    /// assert_eq!(items, Err("inner"));
+    /// # });
+    /// ```
+    fn try_flatten_always_send(self) -> AlwaysSend<futures_util::stream::TryFlatten<Self>>
+    where
+        Self::Ok: futures_core::TryStream + Send,
+        <Self::Ok as futures_core::TryStream>::Error: From<Self::Error>,
+    {
+        AlwaysSend::new(futures_util::TryStreamExt::try_flatten(self))
+    }
+
+    /// Threads a state through the fallible asynchronous step `f`, which is called
+    /// for each `Ok` item of this stream and resolves to the new state along with
+    /// the item to yield, like
+    /// [`scan_async_always_send`](crate::StreamExt::scan_async_always_send).
+    ///
+    /// The first error, either from this stream or from `f`, is yielded, and then
+    /// ends the stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use always_send::TryStreamExt as _;
+    /// use futures::stream::{self, StreamExt as _};
+    ///
+    /// let running_total = |total: i32, x: i32| async move {
+    ///     if x < 0 {
+    ///         return Err(format!("negative: {x}"));
+    ///     }
+    ///     Ok((total + x, total + x))
+    /// };
+    ///
+    /// let totals = stream::iter([Ok(1), Ok(2), Ok(3)])
+    ///     .try_scan_always_send(0, running_total)
+    ///     .collect::<Vec<_>>()
+    ///     .await;
+    /// assert_eq!(totals, [Ok(1), Ok(3), Ok(6)]);
+    ///
+    /// let totals = stream::iter([Ok(1), Ok(-2), Ok(3)])
+    ///     .try_scan_always_send(0, running_total)
+    ///     .collect::<Vec<_>>()
+    ///     .await;
+    /// assert_eq!(totals, [Ok(1), Err("negative: -2".to_owned())]);
+    /// # });
+    /// ```
+    fn try_scan_always_send<St, B, Fut, F>(
+        self,
+        init: St,
+        f: F,
+    ) -> AlwaysSend<stream::TryScan<Self, St, Fut, F>>
+    where
+        St: Send,
+        F: FnMut(St, Self::Ok) -> Fut + Send,
+        Fut: futures_core::TryFuture<Ok = (St, B), Error = Self::Error> + Send,
+    {
+        AlwaysSend::new(stream::TryScan::new(self, init, f))
+    }
+}
+
+#[cfg(feature = "stream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+impl<S: futures_core::TryStream + Send> TryStreamExt for S {}
+
+/// Zips together a collection of wrapped streams, yielding one `Vec` of items
+/// per round until the shortest stream ends.
+///
+/// The items in each `Vec` keep the order of the input streams.
+/// With no input streams at all, the resulting stream is empty.
+/// The streams are boxed internally, so they need not be [`Unpin`].
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use always_send::StreamExt as _;
+/// use futures::stream::{self, StreamExt as _};
+///
+/// let zipped = always_send::zip_all_always_send([
+///     stream::iter(vec![1, 2, 3]).always_send(),
+///     stream::iter(vec![10, 20]).always_send(),
+///     stream::iter(vec![100, 200, 300, 400]).always_send(),
+/// ]);
+/// assert_eq!(
+///     zipped.collect::<Vec<_>>().await,
+///     [vec![1, 10, 100], vec![2, 20, 200]],
+/// );
+///
+/// // works with streams that aren't `Unpin`, too
+/// let countdown = |n: u32| {
+///     stream::unfold(n, |n| async move { n.checked_sub(1).map(|n| (n, n)) }).always_send()
+/// };
+/// let zipped = always_send::zip_all_always_send([countdown(3), countdown(2)]);
+/// assert_eq!(zipped.collect::<Vec<_>>().await, [vec![2, 1], vec![1, 0]]);
+/// # });
+/// ```
+#[cfg(all(feature = "alloc", feature = "stream"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "stream"))))]
+pub fn zip_all_always_send<I, S>(streams: I) -> AlwaysSend<stream::ZipAll<S>>
+where
+    I: IntoIterator<Item = AlwaysSend<S>>,
+    S: Stream,
+    S::Item: Send,
+{
+    AlwaysSend::new(stream::ZipAll::new(streams))
+}
+
+/// Recombines the wrapped halves produced by
+/// [`StreamExt::split_always_send`], like
+/// [`reunite`](futures_util::stream::SplitStream::reunite).
+///
+/// Fails, returning both halves, if they don't originate from the same
+/// call to `split_always_send`.
+///
+/// # Examples
+///
+/// ```
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// use always_send::StreamExt as _;
+/// use futures::sink::SinkExt as _;
+/// use futures::stream::StreamExt as _;
+/// use tokio_util::codec::{Framed, LinesCodec};
+///
+/// let (a, b) = tokio::io::duplex(64);
+/// let (sink, stream) = Framed::new(a, LinesCodec::new()).split_always_send::<String>();
+/// let mut peer = Framed::new(b, LinesCodec::new());
+///
+/// let mut framed = always_send::reunite_always_send(sink, stream).unwrap();
+/// framed.send("ping".to_owned()).await.unwrap();
+/// assert_eq!(peer.next().await.unwrap().unwrap(), "ping");
+/// peer.send("pong").await.unwrap();
+/// assert_eq!(framed.next().await.unwrap().unwrap(), "pong");
+/// # }
+/// ```
+#[cfg(all(feature = "sink", feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "sink", feature = "alloc"))))]
+pub fn reunite_always_send<S, Item>(
+    sink: AlwaysSend<futures_util::stream::SplitSink<S, Item>>,
+    stream: AlwaysSend<futures_util::stream::SplitStream<S>>,
+) -> Result<AlwaysSend<S>, futures_util::stream::ReuniteError<S, Item>>
+where
+    S: futures_sink::Sink<Item> + Unpin + Send,
+{
+    stream.inner.reunite(sink.inner).map(AlwaysSend::new)
+}
+
+/// Converts an iterator into a stream yielding its items, like
+/// [`iter`](futures_util::stream::iter).
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::stream::StreamExt as _;
+///
+/// let items = always_send::always_send_stream_iter(vec![1_u32, 2, 3])
+///     .collect::<Vec<_>>()
+///     .await;
+/// assert_eq!(items, [1, 2, 3]);
+/// # });
+/// ```
+#[cfg(feature = "stream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+pub fn always_send_stream_iter<I: IntoIterator>(iter: I) -> AlwaysSend<impl Stream<Item = I::Item>>
+where
+    I::IntoIter: Send,
+    I::Item: Send,
+{
+    AlwaysSend::new(futures_util::stream::iter(iter))
+}
+
+/// Creates a stream that never yields any item, nor ends, like
+/// [`pending`](futures_util::stream::pending).
+///
+/// # Examples
+///
+/// ```
+/// use futures::stream::Stream;
+/// use std::pin::pin;
+/// use std::task::{Context, Poll};
+///
+/// fn assert_send<T: Send>(_: &T) {}
+///
+/// let mut never = pin!(always_send::always_send_stream_pending::<i32>());
+/// assert_send(&never);
+/// let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+/// for _ in 0..100 {
+///     assert_eq!(never.as_mut().poll_next(&mut cx), Poll::Pending);
+/// }
+/// ```
+#[cfg(feature = "stream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+pub fn always_send_stream_pending<T: Send>() -> AlwaysSend<futures_util::stream::Pending<T>> {
+    AlwaysSend::new(futures_util::stream::pending())
+}
+
+/// Creates a stream that endlessly runs the futures produced by `f`,
+/// one after another, yielding their outputs.
+///
+/// This is [`repeat_with`](futures_util::stream::repeat_with) for
+/// asynchronous factories.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::stream::StreamExt as _;
+///
+/// let mut calls = 0;
+/// let items = always_send::always_send_stream_repeat_with(|| {
+///     calls += 1;
+///     let call = calls;
+///     async move { call * 10 }
+/// })
+/// .take(4)
+/// .collect::<Vec<_>>()
+/// .await;
+/// assert_eq!(items, [10, 20, 30, 40]);
+/// # });
+/// ```
+#[cfg(feature = "stream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+pub fn always_send_stream_repeat_with<Fut, F>(f: F) -> AlwaysSend<impl Stream<Item = Fut::Output>>
+where
+    F: FnMut() -> Fut + Send,
+    Fut: Future + Send,
+    Fut::Output: Send,
+{
+    AlwaysSend::new(futures_util::StreamExt::then(
+        futures_util::stream::repeat_with(f),
+        core::convert::identity,
+    ))
+}
+
+/// Wraps sendable value in the [`AlwaysSend<T>`] wrapper,
+/// a shorthand for [`AlwaysSend::new`].
+///
+/// # Examples
+///
+/// ```
+/// use always_send::always_send;
+///
+/// let wrapped = always_send(vec![1, 2, 3]);
+/// assert_eq!(wrapped.inner, [1, 2, 3]);
+/// ```
+pub fn always_send<T: Send>(value: T) -> AlwaysSend<T> {
+    AlwaysSend::new(value)
+}
+
+/// Wraps a future, or with a `stream:` prefix a stream, in the [`AlwaysSend`] wrapper,
+/// checking it's `Send`.
+///
+/// `always_send!(future)` is the same as [`future.always_send()`](FutureExt::always_send),
+/// and `always_send!(stream: stream)` is the same as
+/// [`stream.always_send()`](StreamExt::always_send) (requiring the `stream` feature).
+/// The macro form can be nicer for wrapping long expressions, or `async` blocks.
+///
+/// # Examples
+///
+/// A typical reproduction of [rust-lang/rust#89976](https://github.com/rust-lang/rust/issues/89976)
+/// fails to compile with "implementation of `FnOnce` is not general enough":
+///
+/// ```compile_fail
+/// // this error has no error code to check for, so apart from the missing
+/// // `always_send!` calls, this is kept identical to the working example below
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// use always_send::always_send;
+/// use futures::stream::{self, StreamExt as _};
+///
+/// let handle = tokio::spawn(async {
+///     let v = vec![1, 2, 3];
+///     let items: Vec<i32> = stream::iter(&v)
+///         .map(|x| async move { *x })
+///         .buffer_unordered(2)
+///         .collect::<Vec<_>>()
+///         .await;
+///     items.len()
+/// });
+/// assert_eq!(handle.await.unwrap(), 3);
+/// # }
+/// ```
+///
+/// Wrapping the future close to the closure fixes it:
+///
+/// ```
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// use always_send::always_send;
+/// use futures::stream::{self, StreamExt as _};
+///
+/// let handle = tokio::spawn(always_send!(async {
+///     let v = vec![1, 2, 3];
+///     let items: Vec<i32> = always_send!(stream::iter(&v)
+///         .map(|x| async move { *x })
+///         .buffer_unordered(2)
+///         .collect::<Vec<_>>())
+///     .await;
+///     items.len()
+/// }));
+/// assert_eq!(handle.await.unwrap(), 3);
+/// # }
+/// ```
+///
+/// With the `stream` feature, `always_send!(stream: …)` applied to the
+/// `.buffer_unordered(2)` stream works, too:
+///
+/// ```
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// # #[cfg(feature = "stream")] {
+/// use always_send::always_send;
+/// use futures::stream::{self, StreamExt as _};
+///
+/// let handle = tokio::spawn(async {
+///     let v = vec![1, 2, 3];
+///     let items: Vec<i32> = always_send!(stream: stream::iter(&v)
+///         .map(|x| async move { *x })
+///         .buffer_unordered(2))
+///     .collect::<Vec<_>>()
+///     .await;
+///     items.len()
+/// });
+/// assert_eq!(handle.await.unwrap(), 3);
+/// # }
+/// # }
+/// ```
+///
+/// Wrapping only the outer `async` block, on the other hand, is *not* enough:
+///
+/// ```compile_fail
+/// // fails with the same error as the first example, which has no error code
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// use always_send::always_send;
+/// use futures::stream::{self, StreamExt as _};
+///
+/// let handle = tokio::spawn(always_send!(async {
+///     let v = vec![1, 2, 3];
+///     let items: Vec<i32> = stream::iter(&v)
+///         .map(|x| async move { *x })
+///         .buffer_unordered(2)
+///         .collect::<Vec<_>>()
+///         .await;
+///     items.len()
+/// }));
+/// assert_eq!(handle.await.unwrap(), 3);
+/// # }
+/// ```
+///
+/// A genuinely non-`Send` future is still rejected:
+///
+/// ```compile_fail,E0277
+/// use always_send::always_send;
+/// use std::rc::Rc;
+///
+/// let rc = Rc::new(());
+/// let _ = always_send!(async move { drop(rc) });
+/// ```
+#[macro_export]
+macro_rules! always_send {
+    (stream: $stream:expr $(,)?) => {
+        $crate::StreamExt::always_send($stream)
+    };
+    ($future:expr $(,)?) => {
+        $crate::FutureExt::always_send($future)
+    };
+}
+
+/// Wraps sendable closure in the [`AlwaysSendFn<F>`] wrapper,
+/// a shorthand for [`AlwaysSendFn::new`].
+///
+/// # Examples
+///
+/// ```
+/// use always_send::always_send_fn;
+///
+/// let offset = 10;
+/// let add = always_send_fn(move |x: i32| x + offset);
+/// assert_eq!((add.inner)(5), 15);
+/// ```
+pub fn always_send_fn<F: Send>(f: F) -> AlwaysSendFn<F> {
+    AlwaysSendFn::new(f)
+}
+
+/// Does nothing, but only compiles if `T` is `Unpin`.
+///
+/// See also [`AlwaysSend::assert_unpin`] for use in method chains.
+///
+/// # Examples
+///
+/// ```
+/// use always_send::{is_unpin, AlwaysSend};
+///
+/// const _: () = is_unpin::<AlwaysSend<u32>>();
+/// ```
+///
+/// ```compile_fail,E0277
+/// use always_send::{is_unpin, AlwaysSend};
+///
+/// const _: () = is_unpin::<AlwaysSend<std::marker::PhantomPinned>>();
+/// ```
+pub const fn is_unpin<T: ?Sized + Unpin>() {}
@@ -0,0 +1,79 @@
+use core::future::Future;
+use core::marker::{PhantomData, PhantomPinned};
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+/// Variant of [`AlwaysSend`](crate::AlwaysSend) that is always `!Unpin`.
+///
+/// Like `AlwaysSend`, this type only requires `T: Send` on construction,
+/// implements `Send` unconditionally, and features an *invariant* type parameter.
+/// Additionally, it contains [`PhantomPinned`], so it isn't `Unpin`, regardless of `T`.
+/// This allows enforcing pinning on wrapped state, e.g. for testing structural pinning.
+///
+/// # Examples
+///
+/// ```
+/// use always_send::AlwaysSendPinned;
+///
+/// fn assert_send<T: Send>(_: &T) {}
+///
+/// let mut wrapped = Box::pin(AlwaysSendPinned::new(42_u32));
+/// assert_send(&wrapped);
+/// assert_eq!(*wrapped.as_mut().inner_pin_mut(), 42);
+/// ```
+///
+/// whereas it's not `Unpin`, even for `T = u32`
+///
+/// ```compile_fail,E0277
+/// use always_send::AlwaysSendPinned;
+///
+/// always_send::is_unpin::<AlwaysSendPinned<u32>>();
+/// ```
+#[repr(transparent)]
+pub struct AlwaysSendPinned<T> {
+    /// The inner value is publicly accessible, but once the wrapper is pinned,
+    /// it can no longer be moved out or accessed by `&mut`; pinned access is available
+    /// through [`inner_pin`](Self::inner_pin) and [`inner_pin_mut`](Self::inner_pin_mut),
+    /// and plain shared access through `&self.inner` still works.
+    pub inner: T,
+    marker: PhantomData<fn() -> *mut T>,
+    _pinned: PhantomPinned,
+}
+
+// SAFETY: the only way of obtaining an `AlwaysSendPinned` is the `T: Send`-bound
+// constructor below, the same reasoning as for `AlwaysSend` applies.
+unsafe impl<T> Send for AlwaysSendPinned<T> {}
+
+impl<T: Send> AlwaysSendPinned<T> {
+    /// Wraps sendable type in the [`AlwaysSendPinned<T>`] wrapper.
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner,
+            marker: PhantomData,
+            _pinned: PhantomPinned,
+        }
+    }
+}
+
+impl<T> AlwaysSendPinned<T> {
+    /// Pinned access to <code>self.[inner][Self::inner]</code>.
+    pub fn inner_pin(self: Pin<&Self>) -> Pin<&T> {
+        // SAFETY: field is structurally pinned
+        unsafe { self.map_unchecked(|this| &this.inner) }
+    }
+
+    /// Pinned mutable access to <code>self.[inner][Self::inner]</code>.
+    pub fn inner_pin_mut(self: Pin<&mut Self>) -> Pin<&mut T> {
+        // SAFETY: field is structurally pinned
+        unsafe { self.map_unchecked_mut(|this| &mut this.inner) }
+    }
+}
+
+// Future, straightforward delegation
+impl<F: Future> Future for AlwaysSendPinned<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.inner_pin_mut().poll(cx)
+    }
+}
@@ -0,0 +1,88 @@
+//! Stream adapters and futures produced by the extension traits and functions of this crate.
+//!
+//! All of these are typically handed out already wrapped in [`AlwaysSend`](crate::AlwaysSend).
+
+#[cfg(feature = "alloc")]
+mod zip_all;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use zip_all::ZipAll;
+
+#[cfg(feature = "alloc")]
+mod buffer_unordered_indexed;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use buffer_unordered_indexed::BufferUnorderedIndexed;
+
+#[cfg(feature = "alloc")]
+mod buffer_unordered_instrumented;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use buffer_unordered_instrumented::BufferUnorderedInstrumented;
+
+#[cfg(feature = "alloc")]
+mod collect_ordered;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use collect_ordered::CollectOrdered;
+
+#[cfg(feature = "alloc")]
+mod group_by;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use group_by::GroupBy;
+
+// `std` rather than `alloc`, for the `std::sync::Mutex` shared by both branches
+#[cfg(feature = "std")]
+mod tee;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use tee::Tee;
+
+#[cfg(feature = "alloc")]
+mod windows;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use windows::Windows;
+
+mod dedup_by_key;
+pub use dedup_by_key::DedupByKey;
+
+mod fold_with_state;
+pub use fold_with_state::FoldWithState;
+
+mod inspect_indexed;
+pub use inspect_indexed::InspectIndexed;
+
+mod scan_async;
+pub use scan_async::ScanAsync;
+
+mod step_by;
+pub use step_by::StepBy;
+
+mod stream_future;
+pub use stream_future::StreamFuture;
+
+mod sum;
+pub use sum::{Product, Sum};
+
+mod try_scan;
+pub use try_scan::TryScan;
+
+mod unzip;
+pub use unzip::Unzip;
+
+mod yield_between;
+pub use yield_between::YieldBetween;
+
+#[cfg(feature = "tokio")]
+mod chunks_timeout;
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+pub use chunks_timeout::ChunksTimeout;
+
+#[cfg(feature = "futures-timer")]
+mod timeout_each;
+#[cfg(feature = "futures-timer")]
+#[cfg_attr(docsrs, doc(cfg(feature = "futures-timer")))]
+pub use timeout_each::{Elapsed, TimeoutEach};
@@ -0,0 +1,100 @@
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use futures_core::{FusedStream, Stream};
+use futures_util::stream::{BufferUnordered, StreamExt as _};
+
+pin_project_lite::pin_project! {
+    /// Stream for [`StreamExt::buffer_unordered_indexed_always_send`](crate::StreamExt::buffer_unordered_indexed_always_send).
+    #[must_use = "streams do nothing unless polled"]
+    pub struct BufferUnorderedIndexed<S>
+    where
+        S: Stream,
+        S::Item: Future,
+    {
+        #[pin]
+        inner: BufferUnordered<IndexFutures<S>>,
+    }
+}
+
+impl<S> BufferUnorderedIndexed<S>
+where
+    S: Stream,
+    S::Item: Future,
+{
+    pub(crate) fn new(stream: S, n: usize) -> Self {
+        Self {
+            inner: IndexFutures { stream, next: 0 }.buffer_unordered(n),
+        }
+    }
+}
+
+impl<S> Stream for BufferUnorderedIndexed<S>
+where
+    S: Stream,
+    S::Item: Future,
+{
+    type Item = (usize, <S::Item as Future>::Output);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.project().inner.poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<S> FusedStream for BufferUnorderedIndexed<S>
+where
+    S: Stream,
+    S::Item: Future,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+pin_project_lite::pin_project! {
+    // yields each future of `stream` tagged with its index
+    struct IndexFutures<S> {
+        #[pin]
+        stream: S,
+        next: usize,
+    }
+}
+
+impl<S: Stream> Stream for IndexFutures<S> {
+    type Item = Indexed<S::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        Poll::Ready(ready!(this.stream.poll_next(cx)).map(|future| {
+            let index = *this.next;
+            *this.next += 1;
+            Indexed { index, future }
+        }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+pin_project_lite::pin_project! {
+    // attaches `index` to the output of `future`
+    struct Indexed<F> {
+        index: usize,
+        #[pin]
+        future: F,
+    }
+}
+
+impl<F: Future> Future for Indexed<F> {
+    type Output = (usize, F::Output);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        this.future.poll(cx).map(|output| (*this.index, output))
+    }
+}
@@ -0,0 +1,105 @@
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use futures_core::{FusedStream, Stream};
+use futures_util::stream::{BufferUnordered, StreamExt as _};
+
+pin_project_lite::pin_project! {
+    /// Stream for [`StreamExt::buffer_unordered_instrumented_always_send`](crate::StreamExt::buffer_unordered_instrumented_always_send).
+    #[must_use = "streams do nothing unless polled"]
+    pub struct BufferUnorderedInstrumented<S, F>
+    where
+        S: Stream,
+        S::Item: Future,
+        F: FnMut(),
+    {
+        #[pin]
+        inner: BufferUnordered<CountFutures<S, F>>,
+    }
+}
+
+impl<S, F> BufferUnorderedInstrumented<S, F>
+where
+    S: Stream,
+    S::Item: Future,
+    F: FnMut(),
+{
+    pub(crate) fn new(stream: S, n: usize, on_saturated: F) -> Self {
+        // `buffer_unordered` would treat zero as unlimited, so the hook could never fire
+        assert!(n > 0, "`n` must be non-zero");
+        Self {
+            inner: CountFutures {
+                stream,
+                in_flight: 0,
+                n,
+                on_saturated,
+            }
+            .buffer_unordered(n),
+        }
+    }
+}
+
+impl<S, F> Stream for BufferUnorderedInstrumented<S, F>
+where
+    S: Stream,
+    S::Item: Future,
+    F: FnMut(),
+{
+    type Item = <S::Item as Future>::Output;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut inner = self.project().inner;
+        let item = ready!(inner.as_mut().poll_next(cx));
+        if item.is_some() {
+            *inner.get_pin_mut().project().in_flight -= 1;
+        }
+        Poll::Ready(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<S, F> FusedStream for BufferUnorderedInstrumented<S, F>
+where
+    S: Stream,
+    S::Item: Future,
+    F: FnMut(),
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+pin_project_lite::pin_project! {
+    // yields the futures of `stream`, calling `on_saturated` whenever
+    // `in_flight` reaches `n`
+    struct CountFutures<S, F> {
+        #[pin]
+        stream: S,
+        in_flight: usize,
+        n: usize,
+        on_saturated: F,
+    }
+}
+
+impl<S: Stream, F: FnMut()> Stream for CountFutures<S, F> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let future = ready!(this.stream.poll_next(cx));
+        if future.is_some() {
+            *this.in_flight += 1;
+            if *this.in_flight == *this.n {
+                (this.on_saturated)();
+            }
+        }
+        Poll::Ready(future)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
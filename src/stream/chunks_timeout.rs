@@ -0,0 +1,83 @@
+use alloc::vec::Vec;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use core::time::Duration;
+use futures_core::{FusedStream, Stream};
+use tokio::time::Sleep;
+
+pin_project_lite::pin_project! {
+    /// Stream for [`StreamExt::chunks_timeout_always_send`](crate::StreamExt::chunks_timeout_always_send).
+    #[must_use = "streams do nothing unless polled"]
+    pub struct ChunksTimeout<S: Stream> {
+        #[pin]
+        stream: S,
+        #[pin]
+        deadline: Option<Sleep>,
+        items: Vec<S::Item>,
+        max_size: usize,
+        duration: Duration,
+        done: bool,
+    }
+}
+
+impl<S: Stream> ChunksTimeout<S> {
+    pub(crate) fn new(stream: S, max_size: usize, duration: Duration) -> Self {
+        assert!(max_size > 0, "`max_size` must be non-zero");
+        Self {
+            stream,
+            deadline: None,
+            items: Vec::new(),
+            max_size,
+            duration,
+            done: false,
+        }
+    }
+}
+
+impl<S: Stream> Stream for ChunksTimeout<S> {
+    type Item = Vec<S::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        if *this.done {
+            return Poll::Ready(None);
+        }
+        loop {
+            match this.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    if this.items.is_empty() {
+                        this.deadline.set(Some(tokio::time::sleep(*this.duration)));
+                    }
+                    this.items.push(item);
+                    if this.items.len() >= *this.max_size {
+                        this.deadline.set(None);
+                        return Poll::Ready(Some(core::mem::take(this.items)));
+                    }
+                }
+                Poll::Ready(None) => {
+                    *this.done = true;
+                    this.deadline.set(None);
+                    if this.items.is_empty() {
+                        return Poll::Ready(None);
+                    }
+                    return Poll::Ready(Some(core::mem::take(this.items)));
+                }
+                Poll::Pending => break,
+            }
+        }
+        if let Some(deadline) = this.deadline.as_mut().as_pin_mut() {
+            if deadline.poll(cx).is_ready() {
+                this.deadline.set(None);
+                return Poll::Ready(Some(core::mem::take(this.items)));
+            }
+        }
+        Poll::Pending
+    }
+}
+
+impl<S: Stream> FusedStream for ChunksTimeout<S> {
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
@@ -0,0 +1,66 @@
+use alloc::vec::Vec;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use futures_core::{FusedFuture, FusedStream, Stream};
+
+use super::BufferUnorderedIndexed;
+
+type Output<S> = <<S as Stream>::Item as Future>::Output;
+
+pin_project_lite::pin_project! {
+    /// Future for [`StreamExt::collect_ordered_always_send`](crate::StreamExt::collect_ordered_always_send).
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct CollectOrdered<S>
+    where
+        S: Stream,
+        S::Item: Future,
+    {
+        #[pin]
+        stream: BufferUnorderedIndexed<S>,
+        // outputs so far, tagged with their indices, in completion order
+        outputs: Vec<(usize, Output<S>)>,
+    }
+}
+
+impl<S> CollectOrdered<S>
+where
+    S: Stream,
+    S::Item: Future,
+{
+    pub(crate) fn new(stream: S, n: usize) -> Self {
+        Self {
+            stream: BufferUnorderedIndexed::new(stream, n),
+            outputs: Vec::new(),
+        }
+    }
+}
+
+impl<S> Future for CollectOrdered<S>
+where
+    S: Stream,
+    S::Item: Future,
+{
+    type Output = Vec<Output<S>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        while let Some(output) = ready!(this.stream.as_mut().poll_next(cx)) {
+            this.outputs.push(output);
+        }
+        let mut outputs = core::mem::take(this.outputs);
+        // indices are unique, so an unstable sort is fine
+        outputs.sort_unstable_by_key(|&(index, _)| index);
+        Poll::Ready(outputs.into_iter().map(|(_, output)| output).collect())
+    }
+}
+
+impl<S> FusedFuture for CollectOrdered<S>
+where
+    S: Stream,
+    S::Item: Future,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
@@ -0,0 +1,66 @@
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use futures_core::{FusedStream, Stream};
+
+pin_project_lite::pin_project! {
+    /// Stream for [`StreamExt::dedup_by_key_always_send`](crate::StreamExt::dedup_by_key_always_send).
+    #[must_use = "streams do nothing unless polled"]
+    pub struct DedupByKey<S, F, K> {
+        #[pin]
+        stream: S,
+        key_fn: F,
+        // key of the last yielded item
+        last: Option<K>,
+    }
+}
+
+impl<S, F, K> DedupByKey<S, F, K> {
+    pub(crate) fn new(stream: S, key_fn: F) -> Self {
+        Self {
+            stream,
+            key_fn,
+            last: None,
+        }
+    }
+}
+
+impl<S, F, K> Stream for DedupByKey<S, F, K>
+where
+    S: Stream,
+    F: FnMut(&S::Item) -> K,
+    K: PartialEq,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<S::Item>> {
+        let mut this = self.project();
+        loop {
+            let Some(item) = ready!(this.stream.as_mut().poll_next(cx)) else {
+                return Poll::Ready(None);
+            };
+            let key = (this.key_fn)(&item);
+            if this.last.as_ref() != Some(&key) {
+                *this.last = Some(key);
+                return Poll::Ready(Some(item));
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.stream.size_hint();
+        // all remaining items could be duplicates of the last one
+        let lower = if self.last.is_none() { lower.min(1) } else { 0 };
+        (lower, upper)
+    }
+}
+
+impl<S, F, K> FusedStream for DedupByKey<S, F, K>
+where
+    S: FusedStream,
+    F: FnMut(&S::Item) -> K,
+    K: PartialEq,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
@@ -0,0 +1,63 @@
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use futures_core::{FusedFuture, Stream};
+
+pin_project_lite::pin_project! {
+    /// Future for [`StreamExt::fold_with_state_always_send`](crate::StreamExt::fold_with_state_always_send).
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct FoldWithState<S, Acc, F> {
+        #[pin]
+        stream: S,
+        acc: Option<Acc>,
+        count: usize,
+        f: F,
+    }
+}
+
+impl<S, Acc, F> FoldWithState<S, Acc, F> {
+    pub(crate) fn new(stream: S, init: Acc, f: F) -> Self {
+        Self {
+            stream,
+            acc: Some(init),
+            count: 0,
+            f,
+        }
+    }
+}
+
+impl<S, Acc, F> Future for FoldWithState<S, Acc, F>
+where
+    S: Stream,
+    F: FnMut(Acc, S::Item) -> Acc,
+{
+    type Output = (Acc, usize);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        loop {
+            let item = ready!(this.stream.as_mut().poll_next(cx));
+            let acc = this
+                .acc
+                .take()
+                .expect("`FoldWithState` polled after completion");
+            match item {
+                Some(item) => {
+                    *this.acc = Some((this.f)(acc, item));
+                    *this.count += 1;
+                }
+                None => return Poll::Ready((acc, *this.count)),
+            }
+        }
+    }
+}
+
+impl<S, Acc, F> FusedFuture for FoldWithState<S, Acc, F>
+where
+    S: Stream,
+    F: FnMut(Acc, S::Item) -> Acc,
+{
+    fn is_terminated(&self) -> bool {
+        self.acc.is_none()
+    }
+}
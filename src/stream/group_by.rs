@@ -0,0 +1,70 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use futures_core::{FusedStream, Stream};
+
+pin_project_lite::pin_project! {
+    /// Stream for [`StreamExt::group_by_always_send`](crate::StreamExt::group_by_always_send).
+    #[must_use = "streams do nothing unless polled"]
+    pub struct GroupBy<S: Stream, F> {
+        #[pin]
+        stream: S,
+        group: Vec<S::Item>,
+        same_group: F,
+        done: bool,
+    }
+}
+
+impl<S: Stream, F> GroupBy<S, F> {
+    pub(crate) fn new(stream: S, same_group: F) -> Self {
+        Self {
+            stream,
+            group: Vec::new(),
+            same_group,
+            done: false,
+        }
+    }
+}
+
+impl<S, F> Stream for GroupBy<S, F>
+where
+    S: Stream,
+    F: FnMut(&S::Item, &S::Item) -> bool,
+{
+    type Item = Vec<S::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        if *this.done {
+            return Poll::Ready(None);
+        }
+        loop {
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Some(item) => match this.group.last() {
+                    Some(last) if !(this.same_group)(last, &item) => {
+                        return Poll::Ready(Some(core::mem::replace(this.group, vec![item])));
+                    }
+                    _ => this.group.push(item),
+                },
+                None => {
+                    *this.done = true;
+                    if this.group.is_empty() {
+                        return Poll::Ready(None);
+                    }
+                    return Poll::Ready(Some(core::mem::take(this.group)));
+                }
+            }
+        }
+    }
+}
+
+impl<S, F> FusedStream for GroupBy<S, F>
+where
+    S: Stream,
+    F: FnMut(&S::Item, &S::Item) -> bool,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
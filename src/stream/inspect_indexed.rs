@@ -0,0 +1,53 @@
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use futures_core::{FusedStream, Stream};
+
+pin_project_lite::pin_project! {
+    /// Stream for [`StreamExt::inspect_indexed_always_send`](crate::StreamExt::inspect_indexed_always_send).
+    #[must_use = "streams do nothing unless polled"]
+    pub struct InspectIndexed<S, F> {
+        #[pin]
+        stream: S,
+        f: F,
+        // index of the next item
+        next: usize,
+    }
+}
+
+impl<S, F> InspectIndexed<S, F> {
+    pub(crate) fn new(stream: S, f: F) -> Self {
+        Self { stream, f, next: 0 }
+    }
+}
+
+impl<S, F> Stream for InspectIndexed<S, F>
+where
+    S: Stream,
+    F: FnMut(usize, &S::Item),
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<S::Item>> {
+        let this = self.project();
+        let item = ready!(this.stream.poll_next(cx));
+        if let Some(item) = &item {
+            (this.f)(*this.next, item);
+            *this.next += 1;
+        }
+        Poll::Ready(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+impl<S, F> FusedStream for InspectIndexed<S, F>
+where
+    S: FusedStream,
+    F: FnMut(usize, &S::Item),
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
@@ -0,0 +1,77 @@
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use futures_core::{FusedStream, Stream};
+
+pin_project_lite::pin_project! {
+    /// Stream for [`StreamExt::scan_async_always_send`](crate::StreamExt::scan_async_always_send).
+    #[must_use = "streams do nothing unless polled"]
+    pub struct ScanAsync<S, St, Fut, F> {
+        #[pin]
+        stream: S,
+        // `None` while the state is moved into `future`
+        state: Option<St>,
+        #[pin]
+        future: Option<Fut>,
+        f: F,
+    }
+}
+
+impl<S, St, Fut, F> ScanAsync<S, St, Fut, F> {
+    pub(crate) fn new(stream: S, init: St, f: F) -> Self {
+        Self {
+            stream,
+            state: Some(init),
+            future: None,
+            f,
+        }
+    }
+}
+
+impl<S, St, B, Fut, F> Stream for ScanAsync<S, St, Fut, F>
+where
+    S: Stream,
+    F: FnMut(St, S::Item) -> Fut,
+    Fut: Future<Output = (St, B)>,
+{
+    type Item = B;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<B>> {
+        let mut this = self.project();
+        loop {
+            if let Some(future) = this.future.as_mut().as_pin_mut() {
+                let (state, item) = ready!(future.poll(cx));
+                this.future.set(None);
+                *this.state = Some(state);
+                return Poll::Ready(Some(item));
+            }
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Some(item) => {
+                    let state = this.state.take().unwrap();
+                    this.future.set(Some((this.f)(state, item)));
+                }
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = usize::from(self.future.is_some());
+        let (lower, upper) = self.stream.size_hint();
+        (
+            lower.saturating_add(pending),
+            upper.and_then(|upper| upper.checked_add(pending)),
+        )
+    }
+}
+
+impl<S, St, B, Fut, F> FusedStream for ScanAsync<S, St, Fut, F>
+where
+    S: FusedStream,
+    F: FnMut(St, S::Item) -> Fut,
+    Fut: Future<Output = (St, B)>,
+{
+    fn is_terminated(&self) -> bool {
+        self.future.is_none() && self.stream.is_terminated()
+    }
+}
@@ -0,0 +1,64 @@
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use futures_core::{FusedStream, Stream};
+
+pin_project_lite::pin_project! {
+    /// Stream for [`StreamExt::step_by_always_send`](crate::StreamExt::step_by_always_send).
+    #[must_use = "streams do nothing unless polled"]
+    pub struct StepBy<S> {
+        #[pin]
+        stream: S,
+        // number of items skipped between yielded ones
+        step_minus_one: usize,
+        // number of items to skip before yielding the next one
+        skip: usize,
+    }
+}
+
+impl<S> StepBy<S> {
+    pub(crate) fn new(stream: S, step: usize) -> Self {
+        assert!(step != 0, "`step` must be non-zero");
+        Self {
+            stream,
+            step_minus_one: step - 1,
+            skip: 0,
+        }
+    }
+
+    // number of items yielded out of `remaining` items of the inner stream
+    fn yielded(&self, remaining: usize) -> usize {
+        match remaining.checked_sub(self.skip) {
+            None | Some(0) => 0,
+            Some(rest) => (rest - 1) / (self.step_minus_one + 1) + 1,
+        }
+    }
+}
+
+impl<S: Stream> Stream for StepBy<S> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<S::Item>> {
+        let mut this = self.project();
+        loop {
+            let Some(item) = ready!(this.stream.as_mut().poll_next(cx)) else {
+                return Poll::Ready(None);
+            };
+            if *this.skip == 0 {
+                *this.skip = *this.step_minus_one;
+                return Poll::Ready(Some(item));
+            }
+            *this.skip -= 1;
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.stream.size_hint();
+        (self.yielded(lower), upper.map(|upper| self.yielded(upper)))
+    }
+}
+
+impl<S: FusedStream> FusedStream for StepBy<S> {
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
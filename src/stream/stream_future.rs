@@ -0,0 +1,39 @@
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use futures_core::{FusedFuture, Stream};
+
+use crate::AlwaysSend;
+
+/// Future for [`StreamExt::into_future_always_send`](crate::StreamExt::into_future_always_send).
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct StreamFuture<S> {
+    stream: Option<AlwaysSend<S>>,
+}
+
+impl<S: Send> StreamFuture<S> {
+    pub(crate) fn new(stream: S) -> Self {
+        Self {
+            stream: Some(AlwaysSend::new(stream)),
+        }
+    }
+}
+
+impl<S: Stream + Unpin> Future for StreamFuture<S> {
+    type Output = (Option<S::Item>, AlwaysSend<S>);
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let stream = self
+            .stream
+            .as_mut()
+            .expect("`StreamFuture` polled after completion");
+        let item = ready!(Pin::new(stream).poll_next(cx));
+        Poll::Ready((item, self.stream.take().unwrap()))
+    }
+}
+
+impl<S: Stream + Unpin> FusedFuture for StreamFuture<S> {
+    fn is_terminated(&self) -> bool {
+        self.stream.is_none()
+    }
+}
@@ -0,0 +1,109 @@
+use core::future::Future;
+use core::iter;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use futures_core::{FusedFuture, Stream};
+
+pin_project_lite::pin_project! {
+    /// Future for [`StreamExt::sum_always_send`](crate::StreamExt::sum_always_send).
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct Sum<S, T> {
+        #[pin]
+        stream: S,
+        acc: Option<T>,
+    }
+}
+
+impl<S: Stream, T: iter::Sum<S::Item>> Sum<S, T> {
+    pub(crate) fn new(stream: S) -> Self {
+        Self {
+            stream,
+            acc: Some(iter::empty().sum()),
+        }
+    }
+}
+
+impl<S, T> Future for Sum<S, T>
+where
+    S: Stream,
+    T: iter::Sum<S::Item> + iter::Sum<T>,
+{
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        let mut this = self.project();
+        loop {
+            let item = ready!(this.stream.as_mut().poll_next(cx));
+            let acc = this.acc.take().expect("`Sum` polled after completion");
+            match item {
+                Some(item) => {
+                    let item: T = iter::once(item).sum();
+                    *this.acc = Some([acc, item].into_iter().sum());
+                }
+                None => return Poll::Ready(acc),
+            }
+        }
+    }
+}
+
+impl<S, T> FusedFuture for Sum<S, T>
+where
+    S: Stream,
+    T: iter::Sum<S::Item> + iter::Sum<T>,
+{
+    fn is_terminated(&self) -> bool {
+        self.acc.is_none()
+    }
+}
+
+pin_project_lite::pin_project! {
+    /// Future for [`StreamExt::product_always_send`](crate::StreamExt::product_always_send).
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct Product<S, T> {
+        #[pin]
+        stream: S,
+        acc: Option<T>,
+    }
+}
+
+impl<S: Stream, T: iter::Product<S::Item>> Product<S, T> {
+    pub(crate) fn new(stream: S) -> Self {
+        Self {
+            stream,
+            acc: Some(iter::empty().product()),
+        }
+    }
+}
+
+impl<S, T> Future for Product<S, T>
+where
+    S: Stream,
+    T: iter::Product<S::Item> + iter::Product<T>,
+{
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        let mut this = self.project();
+        loop {
+            let item = ready!(this.stream.as_mut().poll_next(cx));
+            let acc = this.acc.take().expect("`Product` polled after completion");
+            match item {
+                Some(item) => {
+                    let item: T = iter::once(item).product();
+                    *this.acc = Some([acc, item].into_iter().product());
+                }
+                None => return Poll::Ready(acc),
+            }
+        }
+    }
+}
+
+impl<S, T> FusedFuture for Product<S, T>
+where
+    S: Stream,
+    T: iter::Product<S::Item> + iter::Product<T>,
+{
+    fn is_terminated(&self) -> bool {
+        self.acc.is_none()
+    }
+}
@@ -0,0 +1,126 @@
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use alloc::sync::Arc;
+use alloc::task::Wake;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use futures_core::Stream;
+use futures_util::task::AtomicWaker;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+/// Stream for [`StreamExt::tee_always_send`](crate::StreamExt::tee_always_send).
+///
+/// One of the two branches sharing the original stream.
+/// This requires the `std` feature, for the [`std::sync::Mutex`] around that stream.
+#[must_use = "streams do nothing unless polled"]
+pub struct Tee<S: Stream> {
+    shared: Arc<Shared<S>>,
+    // which of the two branches this is, `0` or `1`
+    index: usize,
+}
+
+struct Shared<S: Stream> {
+    // only ever locked briefly, for a single poll of the inner stream
+    state: Mutex<State<S>>,
+    wakers: Arc<Wakers>,
+}
+
+struct State<S: Stream> {
+    stream: Pin<Box<S>>,
+    // items already pulled from `stream`, but not yet yielded by the respective branch
+    buffers: [VecDeque<S::Item>; 2],
+    dropped: [bool; 2],
+    done: bool,
+}
+
+// the inner stream is polled with a waker for both branches,
+// since either of them may be waiting for its next item
+struct Wakers([AtomicWaker; 2]);
+
+impl Wake for Wakers {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.0[0].wake();
+        self.0[1].wake();
+    }
+}
+
+impl<S: Stream> Shared<S> {
+    fn lock(&self) -> MutexGuard<'_, State<S>> {
+        // a panic of the inner stream leaves nothing half-updated in `State`
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<S: Stream> Tee<S> {
+    pub(crate) fn new(stream: S) -> (Self, Self) {
+        let shared = Arc::new(Shared {
+            state: Mutex::new(State {
+                stream: Box::pin(stream),
+                buffers: [VecDeque::new(), VecDeque::new()],
+                dropped: [false; 2],
+                done: false,
+            }),
+            wakers: Arc::new(Wakers([AtomicWaker::new(), AtomicWaker::new()])),
+        });
+        let first = Self {
+            shared: shared.clone(),
+            index: 0,
+        };
+        let second = Self { shared, index: 1 };
+        (first, second)
+    }
+}
+
+impl<S> Stream for Tee<S>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<S::Item>> {
+        let (this, other) = (self.index, 1 - self.index);
+        self.shared.wakers.0[this].register(cx.waker());
+        let mut state = self.shared.lock();
+        let state = &mut *state;
+        if let Some(item) = state.buffers[this].pop_front() {
+            return Poll::Ready(Some(item));
+        }
+        if state.done {
+            return Poll::Ready(None);
+        }
+        let waker = Waker::from(self.shared.wakers.clone());
+        match state
+            .stream
+            .as_mut()
+            .poll_next(&mut Context::from_waker(&waker))
+        {
+            Poll::Ready(Some(item)) => {
+                if !state.dropped[other] {
+                    state.buffers[other].push_back(item.clone());
+                    self.shared.wakers.0[other].wake();
+                }
+                Poll::Ready(Some(item))
+            }
+            Poll::Ready(None) => {
+                state.done = true;
+                self.shared.wakers.0[other].wake();
+                Poll::Ready(None)
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl<S: Stream> Drop for Tee<S> {
+    fn drop(&mut self) {
+        // stop buffering items for this branch, and drop the ones already buffered
+        let mut state = self.shared.lock();
+        state.dropped[self.index] = true;
+        state.buffers[self.index].clear();
+    }
+}
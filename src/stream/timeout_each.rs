@@ -0,0 +1,65 @@
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use core::time::Duration;
+use futures_core::{FusedStream, Stream};
+use futures_timer::Delay;
+
+pin_project_lite::pin_project! {
+    /// Stream for [`StreamExt::timeout_each_always_send`](crate::StreamExt::timeout_each_always_send).
+    #[must_use = "streams do nothing unless polled"]
+    pub struct TimeoutEach<S> {
+        #[pin]
+        stream: S,
+        // running while waiting for the next item
+        delay: Option<Delay>,
+        duration: Duration,
+    }
+}
+
+impl<S> TimeoutEach<S> {
+    pub(crate) fn new(stream: S, duration: Duration) -> Self {
+        Self {
+            stream,
+            delay: None,
+            duration,
+        }
+    }
+}
+
+impl<S: Stream> Stream for TimeoutEach<S> {
+    type Item = Result<S::Item, Elapsed>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        if let Poll::Ready(item) = this.stream.poll_next(cx) {
+            *this.delay = None;
+            return Poll::Ready(item.map(Ok));
+        }
+        let duration = *this.duration;
+        let delay = this.delay.get_or_insert_with(|| Delay::new(duration));
+        ready!(Pin::new(delay).poll(cx));
+        *this.delay = None;
+        Poll::Ready(Some(Err(Elapsed(()))))
+    }
+}
+
+impl<S: FusedStream> FusedStream for TimeoutEach<S> {
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+/// Error for [`StreamExt::timeout_each_always_send`](crate::StreamExt::timeout_each_always_send),
+/// an item took too long to arrive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elapsed(());
+
+impl fmt::Display for Elapsed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("deadline has elapsed")
+    }
+}
+
+impl core::error::Error for Elapsed {}
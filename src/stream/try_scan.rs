@@ -0,0 +1,90 @@
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use futures_core::{FusedStream, Stream, TryFuture, TryStream};
+
+pin_project_lite::pin_project! {
+    /// Stream for [`TryStreamExt::try_scan_always_send`](crate::TryStreamExt::try_scan_always_send).
+    #[must_use = "streams do nothing unless polled"]
+    pub struct TryScan<S, St, Fut, F> {
+        #[pin]
+        stream: S,
+        // `None` while the state is moved into `future`, or after an error
+        state: Option<St>,
+        #[pin]
+        future: Option<Fut>,
+        f: F,
+    }
+}
+
+impl<S, St, Fut, F> TryScan<S, St, Fut, F> {
+    pub(crate) fn new(stream: S, init: St, f: F) -> Self {
+        Self {
+            stream,
+            state: Some(init),
+            future: None,
+            f,
+        }
+    }
+}
+
+impl<S, St, B, Fut, F> Stream for TryScan<S, St, Fut, F>
+where
+    S: TryStream,
+    F: FnMut(St, S::Ok) -> Fut,
+    Fut: TryFuture<Ok = (St, B), Error = S::Error>,
+{
+    type Item = Result<B, S::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        loop {
+            if let Some(future) = this.future.as_mut().as_pin_mut() {
+                let result = ready!(future.try_poll(cx));
+                this.future.set(None);
+                return Poll::Ready(Some(result.map(|(state, item)| {
+                    *this.state = Some(state);
+                    item
+                })));
+            }
+            if this.state.is_none() {
+                // an earlier error consumed the state
+                return Poll::Ready(None);
+            }
+            match ready!(this.stream.as_mut().try_poll_next(cx)) {
+                Some(Ok(item)) => {
+                    let state = this.state.take().unwrap();
+                    this.future.set(Some((this.f)(state, item)));
+                }
+                Some(Err(e)) => {
+                    *this.state = None;
+                    return Poll::Ready(Some(Err(e)));
+                }
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.future.is_some() {
+            let (_, upper) = self.stream.size_hint();
+            // the pending step might fail, ending the stream
+            (1, upper.and_then(|upper| upper.checked_add(1)))
+        } else if self.state.is_none() {
+            (0, Some(0))
+        } else {
+            let (_, upper) = self.stream.size_hint();
+            (0, upper)
+        }
+    }
+}
+
+impl<S, St, B, Fut, F> FusedStream for TryScan<S, St, Fut, F>
+where
+    S: TryStream + FusedStream,
+    F: FnMut(St, S::Ok) -> Fut,
+    Fut: TryFuture<Ok = (St, B), Error = S::Error>,
+{
+    fn is_terminated(&self) -> bool {
+        self.future.is_none() && (self.state.is_none() || self.stream.is_terminated())
+    }
+}
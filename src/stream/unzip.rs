@@ -0,0 +1,66 @@
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use futures_core::{FusedFuture, Stream};
+
+use crate::AlwaysSend;
+
+pin_project_lite::pin_project! {
+    /// Future for [`StreamExt::unzip_always_send`](crate::StreamExt::unzip_always_send).
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct Unzip<S, CA, CB> {
+        #[pin]
+        stream: S,
+        collections: Option<(CA, CB)>,
+    }
+}
+
+impl<S, CA: Default, CB: Default> Unzip<S, CA, CB> {
+    pub(crate) fn new(stream: S) -> Self {
+        Self {
+            stream,
+            collections: Some(Default::default()),
+        }
+    }
+}
+
+impl<S, A, B, CA, CB> Future for Unzip<S, CA, CB>
+where
+    S: Stream<Item = (A, B)>,
+    CA: Extend<A> + Send,
+    CB: Extend<B> + Send,
+{
+    type Output = (AlwaysSend<CA>, AlwaysSend<CB>);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        loop {
+            let item = ready!(this.stream.as_mut().poll_next(cx));
+            let (left, right) = this
+                .collections
+                .as_mut()
+                .expect("`Unzip` polled after completion");
+            match item {
+                Some((a, b)) => {
+                    left.extend(Some(a));
+                    right.extend(Some(b));
+                }
+                None => {
+                    let (left, right) = this.collections.take().unwrap();
+                    return Poll::Ready((AlwaysSend::new(left), AlwaysSend::new(right)));
+                }
+            }
+        }
+    }
+}
+
+impl<S, A, B, CA, CB> FusedFuture for Unzip<S, CA, CB>
+where
+    S: Stream<Item = (A, B)>,
+    CA: Extend<A> + Send,
+    CB: Extend<B> + Send,
+{
+    fn is_terminated(&self) -> bool {
+        self.collections.is_none()
+    }
+}
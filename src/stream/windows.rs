@@ -0,0 +1,72 @@
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use futures_core::{FusedStream, Stream};
+
+pin_project_lite::pin_project! {
+    /// Stream for [`StreamExt::windows_always_send`](crate::StreamExt::windows_always_send).
+    #[must_use = "streams do nothing unless polled"]
+    pub struct Windows<S: Stream> {
+        #[pin]
+        stream: S,
+        // the last up to `size - 1` items
+        buffer: VecDeque<S::Item>,
+        size: usize,
+    }
+}
+
+impl<S: Stream> Windows<S> {
+    pub(crate) fn new(stream: S, size: usize) -> Self {
+        assert!(size != 0, "`size` must be non-zero");
+        Self {
+            stream,
+            buffer: VecDeque::new(),
+            size,
+        }
+    }
+
+    // number of windows yielded for `remaining` more items of the inner stream
+    fn windows(&self, remaining: usize) -> usize {
+        // exact, since the buffer never holds more than `size - 1` items
+        remaining.saturating_sub(self.size - 1 - self.buffer.len())
+    }
+}
+
+impl<S> Stream for Windows<S>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    type Item = Vec<S::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        loop {
+            let Some(item) = ready!(this.stream.as_mut().poll_next(cx)) else {
+                return Poll::Ready(None);
+            };
+            this.buffer.push_back(item);
+            if this.buffer.len() == *this.size {
+                let window = this.buffer.iter().cloned().collect();
+                this.buffer.pop_front();
+                return Poll::Ready(Some(window));
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.stream.size_hint();
+        (self.windows(lower), upper.map(|upper| self.windows(upper)))
+    }
+}
+
+impl<S> FusedStream for Windows<S>
+where
+    S: FusedStream,
+    S::Item: Clone,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
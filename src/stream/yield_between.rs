@@ -0,0 +1,51 @@
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use futures_core::{FusedStream, Stream};
+
+pin_project_lite::pin_project! {
+    /// Stream for [`StreamExt::yield_between_always_send`](crate::StreamExt::yield_between_always_send).
+    #[must_use = "streams do nothing unless polled"]
+    pub struct YieldBetween<S> {
+        #[pin]
+        stream: S,
+        // whether we already yielded since the last item
+        yielded: bool,
+    }
+}
+
+impl<S> YieldBetween<S> {
+    pub(crate) fn new(stream: S) -> Self {
+        Self {
+            stream,
+            yielded: false,
+        }
+    }
+}
+
+impl<S: Stream> Stream for YieldBetween<S> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<S::Item>> {
+        let this = self.project();
+        if !*this.yielded {
+            *this.yielded = true;
+            cx.waker().wake_by_ref();
+            return Poll::Pending;
+        }
+        let item = ready!(this.stream.poll_next(cx));
+        if item.is_some() {
+            *this.yielded = false;
+        }
+        Poll::Ready(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+impl<S: FusedStream> FusedStream for YieldBetween<S> {
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
@@ -0,0 +1,73 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::{FusedStream, Stream};
+
+use crate::AlwaysSend;
+
+/// Stream for [`zip_all_always_send`](crate::zip_all_always_send).
+#[must_use = "streams do nothing unless polled"]
+pub struct ZipAll<S: Stream> {
+    // boxed, so that the streams need not be `Unpin`
+    streams: Vec<Pin<Box<AlwaysSend<S>>>>,
+    items: Vec<Option<S::Item>>,
+    done: bool,
+}
+
+impl<S: Stream> ZipAll<S> {
+    pub(crate) fn new(streams: impl IntoIterator<Item = AlwaysSend<S>>) -> Self {
+        let streams: Vec<_> = streams.into_iter().map(Box::pin).collect();
+        let items = streams.iter().map(|_| None).collect();
+        let done = streams.is_empty();
+        Self {
+            streams,
+            items,
+            done,
+        }
+    }
+}
+
+// nothing is ever pinned in here; the streams themselves are boxed
+impl<S: Stream> Unpin for ZipAll<S> {}
+
+impl<S: Stream> Stream for ZipAll<S> {
+    type Item = Vec<S::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if this.done {
+            return Poll::Ready(None);
+        }
+        let mut pending = false;
+        for (stream, slot) in this.streams.iter_mut().zip(&mut this.items) {
+            if slot.is_none() {
+                match stream.as_mut().poll_next(cx) {
+                    Poll::Ready(Some(item)) => *slot = Some(item),
+                    Poll::Ready(None) => {
+                        this.done = true;
+                        this.items.clear();
+                        return Poll::Ready(None);
+                    }
+                    Poll::Pending => pending = true,
+                }
+            }
+        }
+        if pending {
+            Poll::Pending
+        } else {
+            Poll::Ready(Some(
+                this.items
+                    .iter_mut()
+                    .map(|slot| slot.take().unwrap())
+                    .collect(),
+            ))
+        }
+    }
+}
+
+impl<S: Stream> FusedStream for ZipAll<S> {
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
@@ -0,0 +1,310 @@
+use core::future::Future;
+use core::marker::PhantomData;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+/// Transparent wrapper type around some [`Sync`] contents.
+///
+/// This is the `Sync` counterpart of [`AlwaysSend`](crate::AlwaysSend):
+/// it only requires `T: Sync` on construction, and then implements `Sync`
+/// unconditionally. This helps in the same kinds of situations, when the compiler
+/// fails to prove that some `&`-borrow captured in a spawned future is `Send`
+/// (i.e. that the borrowed type is `Sync`), even though it plainly is.
+///
+/// Like `AlwaysSend`, this struct features an *invariant* type parameter `T`.
+/// `AlwaysSync<T>` is `Send` exactly if `T` is; for both at once,
+/// see [`AlwaysSendSync`].
+///
+/// # Examples
+///
+/// ```
+/// use always_send::AlwaysSync;
+///
+/// let shared = AlwaysSync::new(vec![1, 2, 3]);
+/// std::thread::scope(|s| {
+///     s.spawn(|| assert_eq!(shared.inner.len(), 3));
+///     s.spawn(|| assert_eq!(shared.inner[0], 1));
+/// });
+/// ```
+#[repr(transparent)]
+pub struct AlwaysSync<T> {
+    /// The inner value is publicly accessible, and there is no [`Drop`] implementation
+    /// so you can have full access to it.
+    ///
+    /// Another (private) field in this struct enforces invariance and prevents construction
+    /// other than through methods such as [`AlwaysSync::new`].
+    pub inner: T,
+    marker: PhantomData<fn() -> *mut T>,
+}
+
+/// The main feature, an implementation of `Sync` *without* requiring `T: Sync`.
+///
+/// This holds for any `T` whatsoever
+/// ```
+/// use always_send::AlwaysSync;
+/// use std::cell::Cell;
+///
+/// const _: fn() = || {
+///     fn assert_sync<T: Sync>() {}
+///     assert_sync::<AlwaysSync<Cell<()>>>();
+/// };
+/// ```
+/// whereas actually constructing a value still requires `T: Sync`
+/// ```compile_fail,E0277
+/// use always_send::AlwaysSync;
+/// use std::cell::Cell;
+///
+/// let _ = AlwaysSync::new(Cell::new(()));
+/// ```
+// SAFETY: all ways of obtaining an (owned or referenced) actual `AlwaysSync` value
+// at runtime need to go through the `T: Sync`-bound API below, and invariance
+// ensures that once it's constructed, it cannot be used to share anything *other*
+// than that same `T`, either.
+unsafe impl<T> Sync for AlwaysSync<T> {}
+
+/// This wrapper offers structural pinning of the [`inner`][AlwaysSync::inner] field.
+impl<T: Unpin> Unpin for AlwaysSync<T> {}
+
+impl<T: Sync> AlwaysSync<T> {
+    /// Wraps shareable type in the [`AlwaysSync<T>`] wrapper.
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner,
+            marker: PhantomData,
+        }
+    }
+
+    /// Wrap as `AlwaysSync` behind a reference.
+    pub fn from_ref(r: &T) -> &Self {
+        // SAFETY: #[repr(transparent)]
+        unsafe { &*(r as *const T as *const Self) }
+    }
+
+    /// Wrap as `AlwaysSync` behind a mutable reference.
+    pub fn from_mut(r: &mut T) -> &mut Self {
+        // SAFETY: #[repr(transparent)]
+        unsafe { &mut *(r as *mut T as *mut Self) }
+    }
+
+    /// Wrap as `AlwaysSync` behind a pinned immutable reference.
+    ///
+    /// To go the other way, see [`.inner_pin()`][Self::inner_pin].
+    pub fn from_pin_ref(r: Pin<&T>) -> Pin<&Self> {
+        // SAFETY: field is structurally pinned
+        unsafe { r.map_unchecked(Self::from_ref) }
+    }
+
+    /// Wrap as `AlwaysSync` behind a pinned mutable reference.
+    ///
+    /// To go the other way, see [`.inner_pin_mut()`][Self::inner_pin_mut].
+    pub fn from_pin_mut(r: Pin<&mut T>) -> Pin<&mut Self> {
+        // SAFETY: field is structurally pinned
+        unsafe { r.map_unchecked_mut(Self::from_mut) }
+    }
+}
+
+impl<T> AlwaysSync<T> {
+    /// Pinned access to <code>self.[inner][Self::inner]</code>.
+    pub fn inner_pin(self: Pin<&Self>) -> Pin<&T> {
+        // SAFETY: field is structurally pinned
+        unsafe { self.map_unchecked(|this| &this.inner) }
+    }
+
+    /// Pinned mutable access to <code>self.[inner][Self::inner]</code>.
+    pub fn inner_pin_mut(self: Pin<&mut Self>) -> Pin<&mut T> {
+        // SAFETY: field is structurally pinned
+        unsafe { self.map_unchecked_mut(|this| &mut this.inner) }
+    }
+}
+
+/// Transparent wrapper type around some [`Send`] and [`Sync`] contents.
+///
+/// This combines [`AlwaysSend`](crate::AlwaysSend) and [`AlwaysSync`]:
+/// it requires `T: Send + Sync` on construction, and then implements
+/// both `Send` and `Sync` unconditionally.
+///
+/// # Examples
+///
+/// ```
+/// use always_send::AlwaysSendSync;
+///
+/// let shared = AlwaysSendSync::new(std::sync::Mutex::new(0));
+/// std::thread::scope(|s| {
+///     s.spawn(|| *shared.inner.lock().unwrap() += 1);
+///     s.spawn(|| *shared.inner.lock().unwrap() += 1);
+/// });
+/// assert_eq!(*shared.inner.lock().unwrap(), 2);
+///
+/// // `Future` (and `Stream`) implementations delegate to the inner value
+/// assert_eq!(futures::executor::block_on(AlwaysSendSync::new(async { 42 })), 42);
+/// ```
+#[repr(transparent)]
+pub struct AlwaysSendSync<T> {
+    /// The inner value is publicly accessible, and there is no [`Drop`] implementation
+    /// so you can have full access to it.
+    ///
+    /// Another (private) field in this struct enforces invariance and prevents construction
+    /// other than through methods such as [`AlwaysSendSync::new`].
+    pub inner: T,
+    marker: PhantomData<fn() -> *mut T>,
+}
+
+/// An implementation of `Send` *without* requiring `T: Send`.
+///
+/// This holds for any `T` whatsoever
+/// ```
+/// use always_send::AlwaysSendSync;
+/// use std::rc::Rc;
+///
+/// const _: fn() = || {
+///     fn assert_send_sync<T: Send + Sync>() {}
+///     assert_send_sync::<AlwaysSendSync<Rc<()>>>();
+/// };
+/// ```
+/// whereas actually constructing a value still requires `T: Send + Sync`
+/// ```compile_fail,E0277
+/// use always_send::AlwaysSendSync;
+/// use std::cell::Cell;
+///
+/// let _ = AlwaysSendSync::new(Cell::new(()));
+/// ```
+// SAFETY: all ways of obtaining an actual `AlwaysSendSync` value at runtime
+// need to go through the `T: Send + Sync`-bound API below; and invariance
+// ensures that it cannot be used to send anything *other* than that same `T`
+unsafe impl<T> Send for AlwaysSendSync<T> {}
+
+/// An implementation of `Sync` *without* requiring `T: Sync`.
+// SAFETY: same as for `Send` above
+unsafe impl<T> Sync for AlwaysSendSync<T> {}
+
+/// This wrapper offers structural pinning of the [`inner`][AlwaysSendSync::inner] field.
+impl<T: Unpin> Unpin for AlwaysSendSync<T> {}
+
+impl<T: Send + Sync> AlwaysSendSync<T> {
+    /// Wraps sendable and shareable type in the [`AlwaysSendSync<T>`] wrapper.
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner,
+            marker: PhantomData,
+        }
+    }
+
+    /// Wrap as `AlwaysSendSync` behind a reference.
+    pub fn from_ref(r: &T) -> &Self {
+        // SAFETY: #[repr(transparent)]
+        unsafe { &*(r as *const T as *const Self) }
+    }
+
+    /// Wrap as `AlwaysSendSync` behind a mutable reference.
+    pub fn from_mut(r: &mut T) -> &mut Self {
+        // SAFETY: #[repr(transparent)]
+        unsafe { &mut *(r as *mut T as *mut Self) }
+    }
+
+    /// Wrap as `AlwaysSendSync` behind a pinned immutable reference.
+    ///
+    /// To go the other way, see [`.inner_pin()`][Self::inner_pin].
+    pub fn from_pin_ref(r: Pin<&T>) -> Pin<&Self> {
+        // SAFETY: field is structurally pinned
+        unsafe { r.map_unchecked(Self::from_ref) }
+    }
+
+    /// Wrap as `AlwaysSendSync` behind a pinned mutable reference.
+    ///
+    /// To go the other way, see [`.inner_pin_mut()`][Self::inner_pin_mut].
+    pub fn from_pin_mut(r: Pin<&mut T>) -> Pin<&mut Self> {
+        // SAFETY: field is structurally pinned
+        unsafe { r.map_unchecked_mut(Self::from_mut) }
+    }
+}
+
+impl<T> AlwaysSendSync<T> {
+    /// Pinned access to <code>self.[inner][Self::inner]</code>.
+    pub fn inner_pin(self: Pin<&Self>) -> Pin<&T> {
+        // SAFETY: field is structurally pinned
+        unsafe { self.map_unchecked(|this| &this.inner) }
+    }
+
+    /// Pinned mutable access to <code>self.[inner][Self::inner]</code>.
+    pub fn inner_pin_mut(self: Pin<&mut Self>) -> Pin<&mut T> {
+        // SAFETY: field is structurally pinned
+        unsafe { self.map_unchecked_mut(|this| &mut this.inner) }
+    }
+}
+
+// Future, straightforward delegation
+impl<F: Future> Future for AlwaysSync<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.inner_pin_mut().poll(cx)
+    }
+}
+
+impl<F: Future> Future for AlwaysSendSync<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.inner_pin_mut().poll(cx)
+    }
+}
+
+#[cfg(feature = "stream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+impl<S: futures_core::Stream> futures_core::Stream for AlwaysSync<S> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner_pin_mut().poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(feature = "stream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+impl<S: futures_core::Stream> futures_core::Stream for AlwaysSendSync<S> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner_pin_mut().poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(feature = "stream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+impl<S: futures_core::FusedStream> futures_core::FusedStream for AlwaysSync<S> {
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+#[cfg(feature = "stream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+impl<S: futures_core::FusedStream> futures_core::FusedStream for AlwaysSendSync<S> {
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+#[cfg(feature = "stream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+impl<F: futures_core::FusedFuture> futures_core::FusedFuture for AlwaysSync<F> {
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+#[cfg(feature = "stream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+impl<F: futures_core::FusedFuture> futures_core::FusedFuture for AlwaysSendSync<F> {
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}